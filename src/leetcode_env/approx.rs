@@ -0,0 +1,218 @@
+//! Comparison of floating point answers, leetcode accepts answers within a
+//! tolerance of the expected answer (usually `1e-5`)
+//!
+//! ```
+//! use cargo_leet::assert_approx_eq;
+//!
+//! assert_approx_eq!(1.0 / 3.0, 0.33333);
+//! assert_approx_eq!(vec![vec![0.5, 2.0 / 3.0]], vec![vec![0.5, 0.66667]]);
+//! assert_approx_eq!(vec![3.14], vec![3.1], 0.1);
+//! ```
+
+use std::fmt::{Debug, Write as _};
+
+/// Tolerance used by [`assert_approx_eq!`](crate::assert_approx_eq) if none
+/// is given
+pub const DEFAULT_APPROX_TOLERANCE: f64 = 1e-5;
+
+/// Maximum number of differences listed when an assertion fails
+const MAX_DIFFERENCES_SHOWN: usize = 10;
+
+/// Values that can be compared within a tolerance, see
+/// [`assert_approx_eq!`](crate::assert_approx_eq)
+pub trait ApproxEq: Debug {
+    /// Adds a description of each difference between `self` and `expected`
+    /// larger than `tolerance` to `differences`
+    ///
+    /// `path` holds the indices of the value in the outer vecs
+    fn approx_differences(
+        &self,
+        expected: &Self,
+        tolerance: f64,
+        path: &mut Vec<usize>,
+        differences: &mut Vec<String>,
+    );
+}
+
+impl ApproxEq for f64 {
+    fn approx_differences(
+        &self,
+        expected: &Self,
+        tolerance: f64,
+        path: &mut Vec<usize>,
+        differences: &mut Vec<String>,
+    ) {
+        let diff = (self - expected).abs();
+        // Equal infinities have a diff of NaN
+        #[allow(clippy::float_cmp)] // exact equality is checked on purpose
+        let is_equal = self == expected || diff <= tolerance;
+        if !is_equal {
+            differences.push(format!(
+                "{}: actual {self:?} but expected {expected:?} (diff {diff:e})",
+                location(path)
+            ));
+        }
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for Vec<T> {
+    fn approx_differences(
+        &self,
+        expected: &Self,
+        tolerance: f64,
+        path: &mut Vec<usize>,
+        differences: &mut Vec<String>,
+    ) {
+        if self.len() != expected.len() {
+            differences.push(format!(
+                "{}: actual has length {} but expected has length {}",
+                location(path),
+                self.len(),
+                expected.len()
+            ));
+            return;
+        }
+        for (i, (actual, expected)) in self.iter().zip(expected).enumerate() {
+            path.push(i);
+            actual.approx_differences(expected, tolerance, path, differences);
+            path.pop();
+        }
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for Option<T> {
+    fn approx_differences(
+        &self,
+        expected: &Self,
+        tolerance: f64,
+        path: &mut Vec<usize>,
+        differences: &mut Vec<String>,
+    ) {
+        match (self, expected) {
+            (Some(actual), Some(expected)) => {
+                actual.approx_differences(expected, tolerance, path, differences);
+            }
+            (None, None) => {}
+            _ => differences.push(format!(
+                "{}: actual {self:?} but expected {expected:?}",
+                location(path)
+            )),
+        }
+    }
+}
+
+fn location(path: &[usize]) -> String {
+    if path.is_empty() {
+        return "value".to_string();
+    }
+    let mut result = "element ".to_string();
+    for i in path {
+        write!(result, "[{i}]").expect("write! macro failed");
+    }
+    result
+}
+
+/// Used by [`assert_approx_eq!`](crate::assert_approx_eq) to build the
+/// message of the panic, `None` if the values are equal within `tolerance`
+#[doc(hidden)]
+#[must_use]
+pub fn approx_eq_failure<T: ApproxEq>(actual: &T, expected: &T, tolerance: f64) -> Option<String> {
+    let mut differences = vec![];
+    actual.approx_differences(expected, tolerance, &mut vec![], &mut differences);
+    if differences.is_empty() {
+        return None;
+    }
+    let mut result = format!("assertion `actual ≈ expected` failed (tolerance {tolerance:e})\n");
+    for difference in differences.iter().take(MAX_DIFFERENCES_SHOWN) {
+        writeln!(result, "  {difference}").expect("write! macro failed");
+    }
+    if differences.len() > MAX_DIFFERENCES_SHOWN {
+        writeln!(
+            result,
+            "  ... and {} more differences",
+            differences.len() - MAX_DIFFERENCES_SHOWN
+        )
+        .expect("write! macro failed");
+    }
+    write!(result, "  actual: {actual:?}\nexpected: {expected:?}").expect("write! macro failed");
+    Some(result)
+}
+
+/// Asserts that two floating point values (or vecs of them) are equal within
+/// a tolerance ([`DEFAULT_APPROX_TOLERANCE`] if not given)
+///
+/// On failure each value outside the tolerance is listed with its index, after
+/// the message if one is given following the tolerance (like [`assert_eq!`])
+///
+/// # Panics
+///
+/// If the values are not equal within the tolerance or vecs have different
+/// lengths
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($actual:expr, $expected:expr $(,)?) => {
+        $crate::assert_approx_eq!($actual, $expected, $crate::DEFAULT_APPROX_TOLERANCE)
+    };
+    ($actual:expr, $expected:expr, $tolerance:expr $(,)?) => {
+        if let Some(message) = $crate::approx_eq_failure(&$actual, &$expected, $tolerance) {
+            panic!("{}", message);
+        }
+    };
+    ($actual:expr, $expected:expr, $tolerance:expr, $($arg:tt)+) => {
+        if let Some(message) = $crate::approx_eq_failure(&$actual, &$expected, $tolerance) {
+            panic!("{}: {}", format_args!($($arg)+), message);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn within_tolerance() {
+        assert_approx_eq!(2.000_001, 2.0);
+        assert_approx_eq!(f64::INFINITY, f64::INFINITY);
+        assert_approx_eq!(vec![Some(1.0), None], vec![Some(1.000_001), None]);
+        assert_approx_eq!(vec![1.0, 1.5], vec![1.1, 1.4], 0.2);
+    }
+
+    #[test]
+    fn failure_lists_differences() {
+        let message = approx_eq_failure(
+            &vec![vec![1.0, 2.0], vec![3.0], vec![]],
+            &vec![vec![1.0, 2.5], vec![3.0, 4.0], vec![]],
+            DEFAULT_APPROX_TOLERANCE,
+        )
+        .unwrap();
+        assert_eq!(
+            message,
+            "assertion `actual ≈ expected` failed (tolerance 1e-5)
+  element [0][1]: actual 2.0 but expected 2.5 (diff 5e-1)
+  element [1]: actual has length 1 but expected has length 2
+  actual: [[1.0, 2.0], [3.0], []]
+expected: [[1.0, 2.5], [3.0, 4.0], []]"
+        );
+    }
+
+    #[test]
+    fn failure_limits_differences() {
+        let message = approx_eq_failure(&vec![0.0; 12], &vec![1.0; 12], 0.5).unwrap();
+        assert!(message.contains("element [9]"));
+        assert!(!message.contains("element [10]"));
+        assert!(message.contains("... and 2 more differences"));
+    }
+
+    #[test]
+    #[should_panic(expected = "value: actual 0.1 but expected 0.2")]
+    fn scalar_failure() {
+        assert_approx_eq!(0.1, 0.2);
+    }
+
+    #[test]
+    #[should_panic(expected = "example 2: assertion `actual ≈ expected` failed")]
+    fn failure_with_message() {
+        let example = 2;
+        assert_approx_eq!(0.1, 0.2, DEFAULT_APPROX_TOLERANCE, "example {example}");
+    }
+}
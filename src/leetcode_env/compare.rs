@@ -0,0 +1,62 @@
+//! Helpers to compare results of problems that accept the answer in any order
+//!
+//! Both the actual and expected values are passed through the same helper
+//! before comparing them
+//!
+//! ```
+//! use cargo_leet::{any_order, any_order_nested};
+//!
+//! assert_eq!(any_order(vec![2, 0, 1]), any_order(vec![0, 1, 2]));
+//! assert_eq!(
+//!     any_order_nested(vec![vec!["tan", "nat"], vec!["bat"]]),
+//!     any_order_nested(vec![vec!["bat"], vec!["nat", "tan"]])
+//! );
+//! ```
+
+/// Sorts `values` so that results returned in any order compare equal
+#[must_use]
+pub fn any_order<T: Ord>(mut values: Vec<T>) -> Vec<T> {
+    values.sort_unstable();
+    values
+}
+
+/// Sorts each inner vec and then the outer vec so that results where neither
+/// the groups nor the items in each group are in a specific order compare
+/// equal
+///
+/// Use [`any_order`] instead if the order inside the groups matters (for
+/// example permutations)
+#[must_use]
+pub fn any_order_nested<T: Ord>(values: Vec<Vec<T>>) -> Vec<Vec<T>> {
+    any_order(values.into_iter().map(any_order).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outer_order_ignored() {
+        assert_eq!(
+            any_order(vec![vec![3, 1], vec![1, 2]]),
+            any_order(vec![vec![1, 2], vec![3, 1]])
+        );
+        assert_ne!(
+            any_order(vec![vec![3, 1], vec![1, 2]]),
+            any_order(vec![vec![2, 1], vec![3, 1]])
+        );
+    }
+
+    #[test]
+    fn inner_order_ignored_when_nested() {
+        assert_eq!(
+            any_order_nested(vec![vec![3, 1], vec![1, 2]]),
+            any_order_nested(vec![vec![2, 1], vec![1, 3]])
+        );
+    }
+
+    #[test]
+    fn duplicates_kept() {
+        assert_ne!(any_order(vec![1, 1, 2]), any_order(vec![1, 2, 2]));
+    }
+}
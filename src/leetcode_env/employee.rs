@@ -0,0 +1,28 @@
+//! Leetcode Employee Support
+//!
+//! Used by [690. Employee Importance](https://leetcode.com/problems/employee-importance/)
+
+/// Definition for an employee as given by leetcode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Employee {
+    /// Unique id of the employee
+    pub id: i32,
+    /// Importance value of the employee
+    pub importance: i32,
+    /// Ids of the direct subordinates of the employee
+    pub subordinates: Vec<i32>,
+}
+
+impl Employee {
+    /// Creates a new [`Employee`] from the values in the order leetcode lists
+    /// them in the examples (e.g. `[1,5,[2,3]]`)
+    #[inline]
+    #[must_use]
+    pub const fn new(id: i32, importance: i32, subordinates: Vec<i32>) -> Self {
+        Self {
+            id,
+            importance,
+            subordinates,
+        }
+    }
+}
@@ -0,0 +1,739 @@
+//! Randomized differential testing of solutions
+//!
+//! Random inputs are generated and passed to both the solution and a simple
+//! (but slow) brute force solution. If they disagree the input is shrunk to a
+//! minimal failing input which is reported in the format leetcode uses for
+//! custom test cases (one argument per line).
+//!
+//! ```
+//! use cargo_leet::Fuzz;
+//!
+//! fn solution(nums: Vec<i32>) -> i32 {
+//!     nums.iter().sum()
+//! }
+//!
+//! fn brute_force(nums: Vec<i32>) -> i32 {
+//!     let mut result = 0;
+//!     for num in nums {
+//!         result += num;
+//!     }
+//!     result
+//! }
+//!
+//! Fuzz::new().differential(
+//!     |g| (g.vec(0..=8, |g| g.i32(-10..=10)),),
+//!     |(nums,)| solution(nums),
+//!     |(nums,)| brute_force(nums),
+//! );
+//! ```
+//!
+//! Generated values are derived from a sequence of random draws. Shrinking
+//! works on those draws (removing them and making them smaller) and the input
+//! is then generated again, so shrunk inputs always respect the ranges passed
+//! to the [`Generator`] and values move towards the value in the range closest
+//! to zero.
+
+use std::{
+    cell::RefCell,
+    fmt::{Debug, Write as _},
+    ops::RangeInclusive,
+    panic::{AssertUnwindSafe, catch_unwind},
+    rc::Rc,
+};
+
+use super::{
+    list::{ListHead, ListNode},
+    nested_integer::NestedInteger,
+    tree::{TreeNode, TreeRoot},
+};
+
+/// Environment variable that can be used to set the seed to reproduce a run
+pub const SEED_ENV_VAR: &str = "CARGO_LEET_SEED";
+
+/// Settings for a randomized test
+#[derive(Debug, Clone)]
+pub struct Fuzz {
+    cases: usize,
+    seed: Option<u64>,
+    max_shrink_attempts: usize,
+}
+
+impl Default for Fuzz {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Fuzz {
+    /// Creates a [`Fuzz`] that runs 1000 cases with a random seed (or the seed
+    /// in [`SEED_ENV_VAR`] if set)
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            cases: 1000,
+            seed: None,
+            max_shrink_attempts: 10_000,
+        }
+    }
+
+    /// Sets the number of random inputs to try
+    #[must_use]
+    pub const fn cases(mut self, cases: usize) -> Self {
+        self.cases = cases;
+        self
+    }
+
+    /// Sets the seed used to generate the inputs
+    #[must_use]
+    pub const fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Checks that `solution` and `brute_force` return the same value for
+    /// inputs created by `generate`
+    ///
+    /// `generate` is called once for each of `solution` and `brute_force` with
+    /// the same random draws so the inputs do not need to be [`Clone`].
+    ///
+    /// # Panics
+    ///
+    /// Panics with the minimal failing input found if the results differ or
+    /// either function panics, or if [`SEED_ENV_VAR`] is set but is not a
+    /// `u64`
+    pub fn differential<I, O, G, S, B>(&self, generate: G, solution: S, brute_force: B)
+    where
+        I: LeetFormat,
+        O: PartialEq + Debug,
+        G: Fn(&mut Generator) -> I,
+        S: Fn(I) -> O,
+        B: Fn(I) -> O,
+    {
+        let seed = self.seed.unwrap_or_else(seed_from_env_or_time);
+        let check = |draws: &[u64]| -> Result<Vec<u64>, Failure> {
+            let mut generator = Generator::replay(draws);
+            let input = generate(&mut generator);
+            let used_draws = generator.draws;
+            let input_text = input.to_leet_string();
+            let actual = catch_unwind(AssertUnwindSafe(|| solution(input)));
+            let expected = catch_unwind(AssertUnwindSafe(|| {
+                brute_force(generate(&mut Generator::replay(draws)))
+            }));
+            let reason = match (actual, expected) {
+                (Ok(actual), Ok(expected)) if actual == expected => return Ok(used_draws),
+                (Ok(actual), Ok(expected)) => {
+                    format!("solution:    {actual:?}\nbrute force: {expected:?}")
+                }
+                (Err(panic), _) => format!("solution panicked: {}", panic_message(&*panic)),
+                (_, Err(panic)) => format!("brute force panicked: {}", panic_message(&*panic)),
+            };
+            Err(Failure {
+                draws: used_draws,
+                input: input_text,
+                reason,
+            })
+        };
+
+        let mut random = SplitMix64(seed);
+        for case in 0..self.cases {
+            let mut generator = Generator::random(random.next());
+            let _ = generate(&mut generator);
+            let Err(failure) = check(&generator.draws) else {
+                continue;
+            };
+            let (failure, steps) = self.shrink(failure, &check);
+            panic!(
+                "Solution and brute force differ on case {case} (seed {seed}, shrunk in {steps} steps)\n\
+                 Input:\n{}\n{}",
+                failure.input, failure.reason
+            );
+        }
+    }
+
+    /// Repeatedly replaces `failure` with a smaller failing input, returns the
+    /// smallest found and the number of steps taken
+    fn shrink<F>(&self, mut failure: Failure, check: &F) -> (Failure, usize)
+    where
+        F: Fn(&[u64]) -> Result<Vec<u64>, Failure>,
+    {
+        let mut steps = 0;
+        let mut attempts = 0;
+        'outer: while attempts < self.max_shrink_attempts {
+            for candidate in shrink_candidates(&failure.draws) {
+                attempts += 1;
+                if attempts > self.max_shrink_attempts {
+                    break 'outer;
+                }
+                // Missing draws are replayed as zero so a candidate can end up
+                // using the same draws, only strictly smaller ones are progress
+                if let Err(smaller) = check(&candidate)
+                    && is_smaller(&smaller.draws, &failure.draws)
+                {
+                    failure = smaller;
+                    steps += 1;
+                    continue 'outer;
+                }
+            }
+            break;
+        }
+        (failure, steps)
+    }
+}
+
+struct Failure {
+    /// The draws that were used to generate the input
+    draws: Vec<u64>,
+    input: String,
+    reason: String,
+}
+
+/// Smaller versions of `draws`, removing draws shrinks collections and
+/// lowering them moves values towards zero
+fn shrink_candidates(draws: &[u64]) -> Vec<Vec<u64>> {
+    let mut result = vec![];
+    for size in [8, 4, 2, 1] {
+        for start in (0..draws.len().saturating_sub(size - 1)).rev() {
+            let mut candidate = draws.to_vec();
+            candidate.drain(start..start + size);
+            result.push(candidate);
+        }
+    }
+    for (i, &draw) in draws.iter().enumerate() {
+        // Values alternate sides of the pivot, subtracting even numbers keeps
+        // the side and makes this a binary search for the smallest failing value
+        let mut smaller = vec![0, draw / 2];
+        smaller.extend(
+            (1..u64::BITS)
+                .rev()
+                .filter_map(|shift| draw.checked_sub(1 << shift)),
+        );
+        smaller.push(draw.saturating_sub(1));
+        smaller.dedup();
+        for value in smaller.into_iter().filter(|&value| value < draw) {
+            let mut candidate = draws.to_vec();
+            candidate[i] = value;
+            result.push(candidate);
+        }
+    }
+    result
+}
+
+/// Orders draws by length first so removing draws is always progress
+fn is_smaller(draws: &[u64], other: &[u64]) -> bool {
+    (draws.len(), draws) < (other.len(), other)
+}
+
+fn panic_message(panic: &(dyn std::any::Any + Send)) -> String {
+    panic
+        .downcast_ref::<&str>()
+        .map(ToString::to_string)
+        .or_else(|| panic.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "<non string panic>".to_string())
+}
+
+/// Seed from [`SEED_ENV_VAR`] if set, otherwise from the current time
+///
+/// # Panics
+///
+/// If the seed is set but is not a `u64`, silently using another seed would
+/// not reproduce the failure the user is after
+fn seed_from_env_or_time() -> u64 {
+    if let Ok(seed) = std::env::var(SEED_ENV_VAR) {
+        return parse_seed(&seed);
+    }
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |duration| {
+            #[allow(clippy::cast_possible_truncation)] // only used as a seed
+            let nanos = duration.as_nanos() as u64;
+            nanos
+        })
+}
+
+fn parse_seed(seed: &str) -> u64 {
+    seed.trim().parse().unwrap_or_else(|e| {
+        panic!("{SEED_ENV_VAR} should be a u64 to reproduce a failure but got {seed:?}: {e}")
+    })
+}
+
+/// Small non cryptographic random number generator (<https://prng.di.unimi.it/splitmix64.c>)
+#[derive(Debug)]
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    const fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// Source of random values used to build the inputs of a test
+#[derive(Debug)]
+pub struct Generator {
+    /// `None` when replaying a fixed sequence of draws
+    random: Option<SplitMix64>,
+    /// Draws to replay, missing draws are treated as zero
+    replay: Vec<u64>,
+    /// Draws made so far (each already reduced to the size of its range)
+    draws: Vec<u64>,
+}
+
+impl Generator {
+    const fn random(seed: u64) -> Self {
+        Self {
+            random: Some(SplitMix64(seed)),
+            replay: vec![],
+            draws: vec![],
+        }
+    }
+
+    fn replay(draws: &[u64]) -> Self {
+        Self {
+            random: None,
+            replay: draws.to_vec(),
+            draws: vec![],
+        }
+    }
+
+    /// Returns a number less than `span` (a `span` of zero means the full
+    /// range of `u64`)
+    fn draw(&mut self, span: u64) -> u64 {
+        let raw = match &mut self.random {
+            Some(random) => random.next(),
+            None => self.replay.get(self.draws.len()).copied().unwrap_or(0),
+        };
+        let result = if span == 0 { raw } else { raw % span };
+        self.draws.push(result);
+        result
+    }
+
+    /// Returns a value in `range`, small draws map to values near the value in
+    /// the range closest to `pivot`
+    fn draw_in_range(&mut self, range: RangeInclusive<i128>, pivot: i128) -> i128 {
+        let (low, high) = range.into_inner();
+        assert!(low <= high, "empty range {low}..={high}");
+        let pivot = pivot.clamp(low, high);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        // Truncates to zero only for the full range of u64 which `draw` treats as such
+        let span = (high - low + 1) as u64;
+        let offset = i128::from(self.draw(span));
+        // Alternate above and below the pivot while there is room on both sides
+        let above = high - pivot;
+        let below = pivot - low;
+        let both_sides = above.min(below);
+        if offset <= 2 * both_sides {
+            if offset % 2 == 1 {
+                pivot + (offset + 1) / 2
+            } else {
+                pivot - offset / 2
+            }
+        } else if above > below {
+            pivot + offset - both_sides
+        } else {
+            pivot - (offset - both_sides)
+        }
+    }
+
+    /// Returns an `i32` in `range`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty
+    pub fn i32(&mut self, range: RangeInclusive<i32>) -> i32 {
+        let (low, high) = range.into_inner();
+        let result = self.draw_in_range(low.into()..=high.into(), 0);
+        i32::try_from(result).expect("value in range of i32")
+    }
+
+    /// Returns an `i64` in `range`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty
+    pub fn i64(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (low, high) = range.into_inner();
+        let result = self.draw_in_range(low.into()..=high.into(), 0);
+        i64::try_from(result).expect("value in range of i64")
+    }
+
+    /// Returns a `u32` in `range`, shrinks towards the start of the range
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty
+    pub fn u32(&mut self, range: RangeInclusive<u32>) -> u32 {
+        let (low, high) = range.into_inner();
+        let result = self.draw_in_range(low.into()..=high.into(), low.into());
+        u32::try_from(result).expect("value in range of u32")
+    }
+
+    /// Returns a `usize` in `range`, shrinks towards the start of the range
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty
+    pub fn usize(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (low, high) = range.into_inner();
+        let low = i128::try_from(low).expect("usize fits in i128");
+        let high = i128::try_from(high).expect("usize fits in i128");
+        let result = self.draw_in_range(low..=high, low);
+        usize::try_from(result).expect("value in range of usize")
+    }
+
+    /// Returns `true` or `false`, shrinks towards `false`
+    pub fn bool(&mut self) -> bool {
+        self.draw(2) == 1
+    }
+
+    /// Returns one of the characters of `alphabet`, shrinks towards the first
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alphabet` is empty
+    pub fn char(&mut self, alphabet: &str) -> char {
+        let chars: Vec<char> = alphabet.chars().collect();
+        assert!(!chars.is_empty(), "alphabet must not be empty");
+        chars[self.usize(0..=chars.len() - 1)]
+    }
+
+    /// Returns a string with a length in `len` made of characters from
+    /// `alphabet`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` or `alphabet` is empty
+    pub fn string(&mut self, len: RangeInclusive<usize>, alphabet: &str) -> String {
+        let len = self.usize(len);
+        (0..len).map(|_| self.char(alphabet)).collect()
+    }
+
+    /// Returns a vec with a length in `len` and each element built by `element`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is empty
+    pub fn vec<T>(
+        &mut self,
+        len: RangeInclusive<usize>,
+        mut element: impl FnMut(&mut Self) -> T,
+    ) -> Vec<T> {
+        let len = self.usize(len);
+        (0..len).map(|_| element(self)).collect()
+    }
+
+    /// Returns a rectangular grid (all rows have the same length) with the
+    /// number of rows in `rows` and of columns in `columns`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows` or `columns` is empty
+    pub fn grid<T>(
+        &mut self,
+        rows: RangeInclusive<usize>,
+        columns: RangeInclusive<usize>,
+        mut element: impl FnMut(&mut Self) -> T,
+    ) -> Vec<Vec<T>> {
+        let rows = self.usize(rows);
+        let columns = self.usize(columns);
+        (0..rows)
+            .map(|_| (0..columns).map(|_| element(self)).collect())
+            .collect()
+    }
+
+    /// Returns a linked list with a length in `len` and values in `values`
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range is empty
+    pub fn list(
+        &mut self,
+        len: RangeInclusive<usize>,
+        values: RangeInclusive<i32>,
+    ) -> Option<Box<ListNode>> {
+        ListHead::from(self.vec(len, |g| g.i32(values.clone()))).into()
+    }
+
+    /// Returns a binary tree of random shape with the number of nodes in `len`
+    /// and values in `values`
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range is empty
+    pub fn tree(
+        &mut self,
+        len: RangeInclusive<usize>,
+        values: RangeInclusive<i32>,
+    ) -> Option<Rc<RefCell<TreeNode>>> {
+        let len = self.usize(len);
+        // Built in level order, each node adds two places for children and
+        // places are only left empty if another one is still available
+        let mut level_order = vec![];
+        let mut available_places = 1;
+        let mut node_count = 0;
+        while node_count < len {
+            if available_places > 1 && self.bool() {
+                level_order.push(None);
+            } else {
+                level_order.push(Some(self.i32(values.clone())));
+                node_count += 1;
+                available_places += 2;
+            }
+            available_places -= 1;
+        }
+        TreeRoot::from(level_order).into()
+    }
+}
+
+/// Conversion into the text format leetcode uses for inputs and outputs
+pub trait LeetFormat {
+    /// Returns the value as leetcode would display it (for tuples one value
+    /// per line like the custom test case input)
+    fn to_leet_string(&self) -> String;
+}
+
+impl LeetFormat for i32 {
+    fn to_leet_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl LeetFormat for i64 {
+    fn to_leet_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl LeetFormat for u32 {
+    fn to_leet_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl LeetFormat for Option<i32> {
+    fn to_leet_string(&self) -> String {
+        self.map_or_else(|| "null".to_string(), |value| value.to_string())
+    }
+}
+
+impl LeetFormat for f64 {
+    fn to_leet_string(&self) -> String {
+        format!("{self:.5}")
+    }
+}
+
+impl LeetFormat for bool {
+    fn to_leet_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl LeetFormat for char {
+    fn to_leet_string(&self) -> String {
+        self.to_string().to_leet_string()
+    }
+}
+
+impl LeetFormat for String {
+    fn to_leet_string(&self) -> String {
+        let mut result = Self::with_capacity(self.len() + 2);
+        result.push('"');
+        for c in self.chars() {
+            if matches!(c, '"' | '\\') {
+                result.push('\\');
+            }
+            result.push(c);
+        }
+        result.push('"');
+        result
+    }
+}
+
+impl<T: LeetFormat> LeetFormat for Vec<T> {
+    fn to_leet_string(&self) -> String {
+        let items: Vec<String> = self.iter().map(LeetFormat::to_leet_string).collect();
+        format!("[{}]", items.join(","))
+    }
+}
+
+impl LeetFormat for NestedInteger {
+    fn to_leet_string(&self) -> String {
+        match self {
+            Self::Int(value) => value.to_string(),
+            Self::List(items) => items.to_leet_string(),
+        }
+    }
+}
+
+impl LeetFormat for Option<Box<ListNode>> {
+    fn to_leet_string(&self) -> String {
+        let mut values = vec![];
+        let mut curr = self;
+        while let Some(node) = curr {
+            values.push(node.val);
+            curr = &node.next;
+        }
+        values.to_leet_string()
+    }
+}
+
+impl LeetFormat for Option<Rc<RefCell<TreeNode>>> {
+    fn to_leet_string(&self) -> String {
+        let level_order: Vec<Option<i32>> = (&TreeRoot::from(self.clone())).into();
+        let mut result = "[".to_string();
+        for (i, value) in level_order.iter().enumerate() {
+            if i > 0 {
+                result.push(',');
+            }
+            match value {
+                Some(value) => write!(result, "{value}").expect("write! macro failed"),
+                None => result.push_str("null"),
+            }
+        }
+        result.push(']');
+        result
+    }
+}
+
+macro_rules! impl_leet_format_for_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: LeetFormat),+> LeetFormat for ($($name,)+) {
+            #[allow(non_snake_case)] // the type names are reused for the values
+            fn to_leet_string(&self) -> String {
+                let ($($name,)+) = self;
+                [$($name.to_leet_string()),+].join("\n")
+            }
+        }
+    };
+}
+
+impl_leet_format_for_tuple!(A);
+impl_leet_format_for_tuple!(A, B);
+impl_leet_format_for_tuple!(A, B, C);
+impl_leet_format_for_tuple!(A, B, C, D);
+impl_leet_format_for_tuple!(A, B, C, D, E);
+impl_leet_format_for_tuple!(A, B, C, D, E, F);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_values_in_range() {
+        let mut generator = Generator::random(42);
+        for _ in 0..1000 {
+            assert!((-3..=5).contains(&generator.i32(-3..=5)));
+            assert!((10..=12).contains(&generator.i64(10..=12)));
+            let grid = generator.grid(1..=3, 2..=4, |g| g.char("xy"));
+            assert!((1..=3).contains(&grid.len()));
+            assert!(grid.iter().all(|row| row.len() == grid[0].len()));
+        }
+        let _ = generator.i32(i32::MIN..=i32::MAX);
+        let _ = generator.usize(0..=usize::MAX);
+    }
+
+    #[test]
+    fn replay_of_zeros_is_closest_to_zero() {
+        let mut generator = Generator::replay(&[]);
+        assert_eq!(generator.i32(-5..=5), 0);
+        assert_eq!(generator.i32(3..=5), 3);
+        assert_eq!(generator.i32(-5..=-3), -3);
+        assert_eq!(generator.string(2..=5, "ab"), "aa");
+    }
+
+    #[test]
+    fn draws_alternate_around_pivot() {
+        let actual: Vec<i32> = (0..6)
+            .map(|draw| Generator::replay(&[draw]).i32(-1..=3))
+            .collect();
+        assert_eq!(actual, [0, 1, -1, 2, 3, 0]);
+    }
+
+    #[test]
+    fn seed_parsed() {
+        assert_eq!(parse_seed(" 42\n"), 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "CARGO_LEET_SEED should be a u64")]
+    fn invalid_seed() {
+        let _ = parse_seed("0x2a");
+    }
+
+    #[test]
+    fn tree_has_requested_number_of_nodes() {
+        let mut generator = Generator::random(7);
+        for n in 0..=10 {
+            for _ in 0..10 {
+                let tree = generator.tree(n..=n, 1..=1);
+                let level_order: Vec<Option<i32>> = (&TreeRoot::from(tree)).into();
+                let count = level_order.iter().flatten().count();
+                assert_eq!(count, n);
+            }
+        }
+    }
+
+    #[test]
+    fn leet_format() {
+        let list: Option<Box<ListNode>> = ListHead::from(vec![1, 2]).into();
+        let tree: Option<Rc<RefCell<TreeNode>>> = TreeRoot::from("[1,null,2]").into();
+        let input = (list, tree, "a\"b".to_string(), vec![vec!['1', '0']]);
+        assert_eq!(
+            input.to_leet_string(),
+            "[1,2]\n[1,null,2]\n\"a\\\"b\"\n[[\"1\",\"0\"]]"
+        );
+    }
+
+    #[test]
+    fn passes_when_equal() {
+        Fuzz::new().cases(100).seed(1).differential(
+            |g| (g.vec(0..=5, |g| g.i32(-10..=10)),),
+            |(nums,)| nums.iter().sum::<i32>(),
+            |(nums,)| {
+                let mut result = 0;
+                for num in nums {
+                    result += num;
+                }
+                result
+            },
+        );
+    }
+
+    #[test]
+    fn reports_minimal_failing_input() {
+        let result = catch_unwind(|| {
+            Fuzz::new().seed(3).differential(
+                |g| (g.vec(0..=20, |g| g.i32(-100..=100)), g.i32(0..=10)),
+                // Wrong if any value is greater than 42
+                |(nums, k)| nums.iter().filter(|&&x| x <= 42).count() + usize::try_from(k).unwrap(),
+                |(nums, k)| nums.len() + usize::try_from(k).unwrap(),
+            );
+        });
+        let message = panic_message(&*result.unwrap_err());
+        assert!(
+            message.ends_with("Input:\n[43]\n0\nsolution:    0\nbrute force: 1"),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn reports_panics() {
+        let result = catch_unwind(|| {
+            Fuzz::new().seed(5).differential(
+                |g| (g.i32(0..=1000),),
+                |(x,)| {
+                    assert!(x < 500, "too big");
+                    x
+                },
+                |(x,)| x,
+            );
+        });
+        let message = panic_message(&*result.unwrap_err());
+        assert!(
+            message.ends_with("Input:\n500\nsolution panicked: too big"),
+            "{message}"
+        );
+    }
+}
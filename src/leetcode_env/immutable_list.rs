@@ -0,0 +1,92 @@
+//! Leetcode Immutable Linked List Support
+//!
+//! Used by
+//! [1265. Print Immutable Linked List in Reverse](https://leetcode.com/problems/print-immutable-linked-list-in-reverse/)
+//! where the list can only be read through its API. What is printed is
+//! recorded per thread (tests run on separate threads so they do not
+//! interfere) and taken with [`take_printed_values`] to check it.
+//!
+//! ```
+//! use cargo_leet::{ImmutableListNode, take_printed_values};
+//!
+//! let head = ImmutableListNode::from(vec![1, 2]);
+//! head.getNext().unwrap().printValue();
+//! head.printValue();
+//! assert_eq!(take_printed_values(), [2, 1]);
+//! ```
+
+use std::cell::RefCell;
+
+thread_local! {
+    static PRINTED: RefCell<Vec<i32>> = const { RefCell::new(vec![]) };
+}
+
+/// Definition of a node of an immutable linked list as given by leetcode,
+/// only accessible through [`Self::printValue`] and [`Self::getNext`]
+#[derive(Debug, PartialEq, Eq)]
+pub struct ImmutableListNode {
+    val: i32,
+    next: Option<Box<Self>>,
+}
+
+#[allow(non_snake_case)] // the names of the methods are from leetcode
+impl ImmutableListNode {
+    /// Prints the value of the node, see [`take_printed_values`]
+    pub fn printValue(&self) {
+        PRINTED.with(|printed| printed.borrow_mut().push(self.val));
+    }
+
+    /// Returns the next node if there is one
+    #[must_use]
+    pub fn getNext(&self) -> Option<&Self> {
+        self.next.as_deref()
+    }
+}
+
+#[allow(clippy::fallible_impl_from)] // we need the function to fail if it doesn't match the expected format
+impl From<Vec<i32>> for ImmutableListNode {
+    /// Builds the list with the values in order
+    ///
+    /// # Panics
+    ///
+    /// If `values` is empty as the list has at least one node
+    fn from(values: Vec<i32>) -> Self {
+        let mut next = None;
+        for val in values.into_iter().rev() {
+            next = Some(Box::new(Self { val, next }));
+        }
+        *next.expect("Expected at least one value for the list")
+    }
+}
+
+/// Returns the values printed by [`ImmutableListNode::printValue`] on the
+/// current thread since the last call
+#[must_use]
+pub fn take_printed_values() -> Vec<i32> {
+    PRINTED.with(RefCell::take)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn printed_in_reverse() {
+        let head = ImmutableListNode::from(vec![1, 2, 3]);
+        let mut nodes = vec![];
+        let mut node = Some(&head);
+        while let Some(current) = node {
+            nodes.push(current);
+            node = current.getNext();
+        }
+        nodes.iter().rev().for_each(|node| node.printValue());
+        assert_eq!(take_printed_values(), [3, 2, 1]);
+        assert!(take_printed_values().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one value")]
+    fn from_empty() {
+        let _ = ImmutableListNode::from(vec![]);
+    }
+}
@@ -0,0 +1,261 @@
+//! Leetcode Interactive Problem Support
+//!
+//! Mocks of the APIs the judge provides for interactive problems like
+//! [374. Guess Number Higher or Lower](https://leetcode.com/problems/guess-number-higher-or-lower/),
+//! [278. First Bad Version](https://leetcode.com/problems/first-bad-version/)
+//! and [1095. Find in Mountain Array](https://leetcode.com/problems/find-in-mountain-array/)
+//!
+//! The value the solution has to find is set per thread with [`set_secret`]
+//! (tests run on separate threads so they do not interfere) and each call to
+//! the API is counted so a limit on the number of calls can be enforced.
+//!
+//! ```
+//! use cargo_leet::{VersionControl as _, guess, judge_call_count, set_secret};
+//!
+//! set_secret(6);
+//! assert_eq!(guess(10), -1);
+//! assert_eq!(guess(6), 0);
+//! assert!(().isBadVersion(7));
+//! assert_eq!(judge_call_count(), 3);
+//! ```
+
+use std::cell::{Cell, RefCell};
+
+/// Number of calls to [`MountainArray::get`] leetcode allows before judging
+/// the answer wrong
+pub const MOUNTAIN_ARRAY_CALL_LIMIT: usize = 100;
+
+#[derive(Debug, Default)]
+struct Judge {
+    secret: Option<i32>,
+    calls: usize,
+    call_limit: Option<usize>,
+}
+
+thread_local! {
+    static JUDGE: RefCell<Judge> = RefCell::new(Judge::default());
+}
+
+/// Sets the value hidden behind the judge APIs for the current thread and
+/// resets the call counter
+///
+/// - [`guess`]: the picked number
+/// - [`VersionControl::isBadVersion`]: the first bad version
+pub fn set_secret(secret: i32) {
+    JUDGE.with(|judge| {
+        let mut judge = judge.borrow_mut();
+        judge.secret = Some(secret);
+        judge.calls = 0;
+    });
+}
+
+/// Limits the number of calls to the judge APIs for the current thread,
+/// exceeding it panics. `None` (the default) removes the limit
+pub fn set_judge_call_limit(call_limit: Option<usize>) {
+    JUDGE.with(|judge| judge.borrow_mut().call_limit = call_limit);
+}
+
+/// Number of calls to the judge APIs since the secret was last set on the
+/// current thread
+#[must_use]
+pub fn judge_call_count() -> usize {
+    JUDGE.with(|judge| judge.borrow().calls)
+}
+
+/// Counts the call and returns the secret
+///
+/// # Panics
+///
+/// If the secret is not set or the call limit is exceeded
+fn call_judge(api: &str) -> i32 {
+    JUDGE.with(|judge| {
+        let mut judge = judge.borrow_mut();
+        judge.calls += 1;
+        if let Some(call_limit) = judge.call_limit {
+            assert!(
+                judge.calls <= call_limit,
+                "`{api}` called more than the limit of {call_limit} times"
+            );
+        }
+        judge
+            .secret
+            .unwrap_or_else(|| panic!("`{api}` called before `cargo_leet::set_secret`"))
+    })
+}
+
+/// Guess API of "Guess Number Higher or Lower"
+///
+/// Returns -1 if `num` is higher than the picked number, 1 if it is lower and
+/// 0 if it is equal
+///
+/// # Panics
+///
+/// If the secret is not set or the call limit is exceeded (see
+/// [`set_secret`])
+#[must_use]
+pub fn guess(num: i32) -> i32 {
+    let pick = call_judge("guess");
+    match num.cmp(&pick) {
+        std::cmp::Ordering::Less => 1,
+        std::cmp::Ordering::Equal => 0,
+        std::cmp::Ordering::Greater => -1,
+    }
+}
+
+/// Version control API of "First Bad Version"
+///
+/// Leetcode makes it available on `Solution` as `self.isBadVersion(version)`,
+/// importing this trait does the same for every type
+pub trait VersionControl {
+    /// Returns `true` if `version` is the first bad version or after it
+    ///
+    /// # Panics
+    ///
+    /// If the secret is not set or the call limit is exceeded (see
+    /// [`set_secret`])
+    #[allow(non_snake_case)] // name used by leetcode
+    fn isBadVersion(&self, version: i32) -> bool {
+        version >= call_judge("isBadVersion")
+    }
+}
+
+impl<T: ?Sized> VersionControl for T {}
+
+/// Mountain array API of "Find in Mountain Array"
+///
+/// Unlike the other judge APIs the calls are counted per array
+#[derive(Debug, Clone)]
+pub struct MountainArray {
+    values: Vec<i32>,
+    calls: Cell<usize>,
+    call_limit: Option<usize>,
+}
+
+impl MountainArray {
+    /// Creates the array with the call limit used by leetcode
+    /// ([`MOUNTAIN_ARRAY_CALL_LIMIT`])
+    #[must_use]
+    pub const fn new(values: Vec<i32>) -> Self {
+        Self {
+            values,
+            calls: Cell::new(0),
+            call_limit: Some(MOUNTAIN_ARRAY_CALL_LIMIT),
+        }
+    }
+
+    /// Replaces the limit on the number of calls to [`Self::get`], `None`
+    /// removes it
+    #[must_use]
+    pub const fn with_call_limit(mut self, call_limit: Option<usize>) -> Self {
+        self.call_limit = call_limit;
+        self
+    }
+
+    /// Returns the value at `index`
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds or the call limit is exceeded
+    #[must_use]
+    pub fn get(&self, index: i32) -> i32 {
+        let calls = self.calls.get() + 1;
+        self.calls.set(calls);
+        if let Some(call_limit) = self.call_limit {
+            assert!(
+                calls <= call_limit,
+                "`MountainArray::get` called more than the limit of {call_limit} times"
+            );
+        }
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| self.values.get(i))
+            .copied()
+            .unwrap_or_else(|| {
+                panic!(
+                    "index {index} out of bounds for a mountain array of length {}",
+                    self.values.len()
+                )
+            })
+    }
+
+    /// Returns the length of the array (not counted as a call)
+    ///
+    /// # Panics
+    ///
+    /// If the length does not fit in an `i32`
+    #[must_use]
+    pub fn length(&self) -> i32 {
+        i32::try_from(self.values.len()).expect("leetcode arrays fit in i32")
+    }
+
+    /// Number of calls to [`Self::get`] so far
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)] // `Cell::get` is not const on the toolchain leetcode uses
+    pub fn call_count(&self) -> usize {
+        self.calls.get()
+    }
+}
+
+impl From<Vec<i32>> for MountainArray {
+    fn from(values: Vec<i32>) -> Self {
+        Self::new(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guess_compares_with_secret() {
+        set_secret(6);
+        assert_eq!(guess(1), 1);
+        assert_eq!(guess(6), 0);
+        assert_eq!(guess(10), -1);
+        assert_eq!(judge_call_count(), 3);
+        set_secret(2);
+        assert_eq!(judge_call_count(), 0);
+    }
+
+    #[test]
+    fn bad_version() {
+        struct Solution;
+        set_secret(4);
+        assert!(!Solution.isBadVersion(3));
+        assert!(Solution.isBadVersion(4));
+        assert!(Solution.isBadVersion(5));
+    }
+
+    #[test]
+    #[should_panic(expected = "called before `cargo_leet::set_secret`")]
+    fn secret_not_set() {
+        let _ = guess(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "`guess` called more than the limit of 2 times")]
+    fn judge_call_limit() {
+        set_secret(1);
+        set_judge_call_limit(Some(2));
+        for _ in 0..3 {
+            let _ = guess(5);
+        }
+    }
+
+    #[test]
+    fn mountain_array() {
+        let array = MountainArray::from(vec![1, 2, 3, 4, 5, 3, 1]);
+        assert_eq!(array.length(), 7);
+        assert_eq!(array.get(4), 5);
+        assert_eq!(array.get(6), 1);
+        assert_eq!(array.call_count(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "called more than the limit of 1 times")]
+    fn mountain_array_call_limit() {
+        let array = MountainArray::new(vec![0, 1, 0]).with_call_limit(Some(1));
+        let _ = array.get(0);
+        let _ = array.get(1);
+    }
+}
@@ -1,5 +1,14 @@
 //! Add support for "types" defined on leetcode and methods to facilitate
 //! conversion from example format
 
+pub(crate) mod approx;
+pub(crate) mod compare;
+pub(crate) mod employee;
+pub(crate) mod fuzz;
+pub(crate) mod immutable_list;
+pub(crate) mod interactive;
 pub(crate) mod list;
+pub(crate) mod nary_tree;
+pub(crate) mod nested_integer;
 pub(crate) mod tree;
+pub(crate) mod validators;
@@ -0,0 +1,141 @@
+//! Leetcode N-ary Tree Support
+//!
+//! Used by problems like
+//! [589. N-ary Tree Preorder Traversal](https://leetcode.com/problems/n-ary-tree-preorder-traversal/)
+
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+
+/// Definition for a node of an N-ary tree
+#[derive(Debug, PartialEq, Eq)]
+pub struct Node {
+    /// The value stored at this node
+    pub val: i32,
+    /// Links to the children of this node in order
+    pub children: Vec<Rc<RefCell<Self>>>,
+}
+
+impl Node {
+    /// Creates a new [`Node`] with no children and the value passed
+    #[inline]
+    #[must_use]
+    pub const fn new(val: i32) -> Self {
+        Self {
+            val,
+            children: vec![],
+        }
+    }
+}
+
+/// Wrapper to build N-ary trees from the level order format used by leetcode
+/// where each group of children is ended by "null"
+#[derive(Debug, PartialEq, Eq)]
+pub struct NaryTreeRoot {
+    /// The root of the tree held
+    pub root: Option<Rc<RefCell<Node>>>,
+}
+
+#[allow(clippy::fallible_impl_from)] // we need the function to fail if it doesn't match the expected format
+impl From<&str> for NaryTreeRoot {
+    /// Expects the "[]" around the values, separated by comma "," and only
+    /// integers and "null" (e.g. `"[1,null,3,2,4,null,5,6]"` which is the
+    /// format you'll get on leetcode)
+    ///
+    /// # Panics
+    ///
+    /// This function panics if it doesn't match the expected format
+    fn from(value: &str) -> Self {
+        let value = value.trim();
+        let inner = value
+            .strip_prefix('[')
+            .and_then(|x| x.strip_suffix(']'))
+            .unwrap_or_else(|| panic!("Expected values in \"[]\" but got {value:?}"));
+        if inner.trim().is_empty() {
+            return Self { root: None };
+        }
+        let mut values = inner.split(',').map(|x| match x.trim() {
+            "null" => None,
+            x => Some(
+                x.parse::<i32>()
+                    .unwrap_or_else(|e| panic!("Expected an integer but got {x:?}: {e}")),
+            ),
+        });
+        let root_val = values
+            .next()
+            .flatten()
+            .unwrap_or_else(|| panic!("Expected the value of the root first in {value:?}"));
+        let root = Rc::new(RefCell::new(Node::new(root_val)));
+
+        // Each "null" moves on to the children of the next node in level order
+        let mut queue = VecDeque::from([Rc::clone(&root)]);
+        let mut parent = None;
+        for val in values {
+            match val {
+                None => parent = queue.pop_front(),
+                Some(val) => {
+                    let child = Rc::new(RefCell::new(Node::new(val)));
+                    parent
+                        .as_ref()
+                        .unwrap_or_else(|| panic!("Expected \"null\" after the root in {value:?}"))
+                        .borrow_mut()
+                        .children
+                        .push(Rc::clone(&child));
+                    queue.push_back(child);
+                }
+            }
+        }
+        Self { root: Some(root) }
+    }
+}
+
+impl From<NaryTreeRoot> for Option<Rc<RefCell<Node>>> {
+    fn from(value: NaryTreeRoot) -> Self {
+        value.root
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(val: i32, children: Vec<Node>) -> Node {
+        Node {
+            val,
+            children: children
+                .into_iter()
+                .map(|child| Rc::new(RefCell::new(child)))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn from_str() {
+        let expected = node(
+            1,
+            vec![
+                node(3, vec![node(5, vec![]), node(6, vec![])]),
+                node(2, vec![]),
+                node(4, vec![]),
+            ],
+        );
+        let actual = NaryTreeRoot::from("[1,null,3,2,4,null,5,6]");
+        assert_eq!(actual.root, Some(Rc::new(RefCell::new(expected))));
+    }
+
+    #[test]
+    fn from_str_skipped_levels() {
+        let expected = node(1, vec![node(2, vec![]), node(3, vec![node(4, vec![])])]);
+        let actual = NaryTreeRoot::from("[1, null, 2, 3, null, null, 4]");
+        assert_eq!(actual.root, Some(Rc::new(RefCell::new(expected))));
+    }
+
+    #[test]
+    fn from_str_empty() {
+        assert_eq!(NaryTreeRoot::from("[]").root, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected \"null\" after the root")]
+    fn from_str_missing_null() {
+        let _ = NaryTreeRoot::from("[1,2]");
+    }
+}
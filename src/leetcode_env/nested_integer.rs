@@ -0,0 +1,92 @@
+//! Leetcode Nested Integer Support
+//!
+//! Used by problems with nested lists like
+//! [385. Mini Parser](https://leetcode.com/problems/mini-parser/)
+
+/// Definition of a nested list of integers as given by leetcode
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum NestedInteger {
+    /// A single integer
+    Int(i32),
+    /// A list of nested integers
+    List(Vec<Self>),
+}
+
+#[allow(clippy::fallible_impl_from)] // we need the function to fail if it doesn't match the expected format
+impl From<&str> for NestedInteger {
+    /// Expects a single integer or lists of them in "[]" separated by comma ","
+    /// (e.g. `"[123,[456,[789]]]"` which is the format you'll get on leetcode)
+    ///
+    /// # Panics
+    ///
+    /// This function panics if it doesn't match the expected format
+    fn from(value: &str) -> Self {
+        let mut chars = value.trim().chars().peekable();
+        let result = parse(&mut chars);
+        assert!(
+            chars.all(char::is_whitespace),
+            "Unexpected characters after the nested integer in {value:?}"
+        );
+        result
+    }
+}
+
+/// Parses the next nested integer removing it from `chars`
+fn parse(chars: &mut std::iter::Peekable<std::str::Chars>) -> NestedInteger {
+    skip_whitespace(chars);
+    if chars.next_if_eq(&'[').is_none() {
+        let mut number = String::new();
+        while let Some(c) = chars.next_if(|&c| c == '-' || c.is_ascii_digit()) {
+            number.push(c);
+        }
+        return NestedInteger::Int(
+            number
+                .parse()
+                .unwrap_or_else(|e| panic!("Expected an integer but got {number:?}: {e}")),
+        );
+    }
+    let mut items = vec![];
+    skip_whitespace(chars);
+    if chars.next_if_eq(&']').is_some() {
+        return NestedInteger::List(items);
+    }
+    loop {
+        items.push(parse(chars));
+        skip_whitespace(chars);
+        match chars.next() {
+            Some(',') => {}
+            Some(']') => return NestedInteger::List(items),
+            c => panic!("Expected ',' or ']' but got {c:?}"),
+        }
+    }
+}
+
+fn skip_whitespace(chars: &mut std::iter::Peekable<std::str::Chars>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str() {
+        use NestedInteger::{Int, List};
+        assert_eq!(NestedInteger::from("324"), Int(324));
+        assert_eq!(NestedInteger::from("[]"), List(vec![]));
+        assert_eq!(
+            NestedInteger::from("[123, [456,[-789]],[]]"),
+            List(vec![
+                Int(123),
+                List(vec![Int(456), List(vec![Int(-789)])]),
+                List(vec![])
+            ])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected ',' or ']'")]
+    fn from_str_unclosed() {
+        let _ = NestedInteger::from("[1,[2]");
+    }
+}
@@ -0,0 +1,86 @@
+//! Validators for problems that accept more than one answer (e.g. "return any
+//! longest increasing subsequence")
+//!
+//! Used by the `check_answer` function generated when a checker is selected
+//! in the module
+//!
+//! ```
+//! use cargo_leet::{is_permutation_of, is_sorted_subsequence_of, is_valid_path_in_grid};
+//!
+//! assert!(is_permutation_of(&[3, 1, 2], &[1, 2, 3]));
+//! assert!(is_sorted_subsequence_of(&[2, 3, 7], &[10, 9, 2, 5, 3, 7, 101]));
+//! let grid = vec![vec![0, 0], vec![1, 0]];
+//! assert!(is_valid_path_in_grid(&grid, &[vec![0, 0], vec![0, 1], vec![1, 1]]));
+//! ```
+
+/// Returns `true` if `actual` has the same items as `expected` in any order
+#[must_use]
+pub fn is_permutation_of<T: Ord>(actual: &[T], expected: &[T]) -> bool {
+    let mut actual: Vec<_> = actual.iter().collect();
+    let mut expected: Vec<_> = expected.iter().collect();
+    actual.sort_unstable();
+    expected.sort_unstable();
+    actual == expected
+}
+
+/// Returns `true` if `actual` is sorted in increasing order (equal items
+/// allowed) and its items appear in `sequence` in the same order
+#[must_use]
+pub fn is_sorted_subsequence_of<T: Ord>(actual: &[T], sequence: &[T]) -> bool {
+    let is_sorted = actual.windows(2).all(|w| w[0] <= w[1]);
+    let mut remaining = sequence.iter();
+    is_sorted && actual.iter().all(|item| remaining.any(|x| x == item))
+}
+
+/// Returns `true` if each cell of `path` (given as `[row, col]`) is in `grid`
+/// and each step moves to one of the 4 neighbouring cells
+#[must_use]
+pub fn is_valid_path_in_grid<T>(grid: &[Vec<T>], path: &[Vec<i32>]) -> bool {
+    let is_in_grid = |cell: &Vec<i32>| match cell[..] {
+        [row, col] => usize::try_from(row)
+            .ok()
+            .and_then(|row| grid.get(row))
+            .zip(usize::try_from(col).ok())
+            .is_some_and(|(row, col)| col < row.len()),
+        _ => false,
+    };
+    path.iter().all(is_in_grid)
+        && path
+            .windows(2)
+            .all(|w| (w[0][0] - w[1][0]).abs() + (w[0][1] - w[1][1]).abs() == 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn permutation() {
+        assert!(is_permutation_of(&[2, 1, 2], &[1, 2, 2]));
+        assert!(!is_permutation_of(&[2, 1, 1], &[1, 2, 2]));
+        assert!(!is_permutation_of(&[1, 2], &[1, 2, 3]));
+    }
+
+    #[test]
+    fn sorted_subsequence() {
+        let nums = [0, 1, 0, 3, 2, 3];
+        assert!(is_sorted_subsequence_of(&[0, 1, 2, 3], &nums));
+        assert!(is_sorted_subsequence_of(&[0, 0, 2], &nums));
+        assert!(!is_sorted_subsequence_of(&[0, 3, 2], &nums));
+        assert!(!is_sorted_subsequence_of(&[1, 1], &nums));
+    }
+
+    #[test]
+    fn path_in_grid() {
+        let grid = vec![vec!['a'; 3]; 2];
+        assert!(is_valid_path_in_grid(
+            &grid,
+            &[vec![1, 2], vec![1, 1], vec![0, 1]]
+        ));
+        assert!(is_valid_path_in_grid(&grid, &[]));
+        assert!(!is_valid_path_in_grid(&grid, &[vec![0, 0], vec![1, 1]]));
+        assert!(!is_valid_path_in_grid(&grid, &[vec![1, 2], vec![2, 2]]));
+        assert!(!is_valid_path_in_grid(&grid, &[vec![0, -1]]));
+        assert!(!is_valid_path_in_grid(&grid, &[vec![0]]));
+    }
+}
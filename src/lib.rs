@@ -28,7 +28,16 @@
 //! the environment that your code would run in on the leetcode servers so that
 //! you are able to run tests on your code locally. It also provides a few extra
 //! types that facilitate testing especially as it relates to creating test
-//! cases from the text provided by leetcode.
+//! cases from the text provided by leetcode. [`Fuzz`] can be used to compare
+//! a solution against a brute force solution on random inputs and
+//! [`any_order`] to compare answers that can be returned in any order. The
+//! APIs of interactive problems (e.g. [`guess`]) are mocked with the value to
+//! find set by [`set_secret`]. Other types leetcode defines for some problems
+//! like [`NestedInteger`], [`Node`] (N-ary trees), [`Employee`] and
+//! [`ImmutableListNode`] are provided too. For problems that accept more than
+//! one answer validators like [`is_permutation_of`] check the answer instead
+//! and [`assert_approx_eq!`] compares floating point answers within a
+//! tolerance.
 //!
 //! ## Feature flags
 //! **cargo-leet** uses feature flags to control which code gets compiled based
@@ -53,8 +62,20 @@
 
 #[cfg(feature = "leet_env")]
 pub use leetcode_env::{
+    approx::{ApproxEq, DEFAULT_APPROX_TOLERANCE, approx_eq_failure},
+    compare::{any_order, any_order_nested},
+    employee::Employee,
+    fuzz::{Fuzz, Generator, LeetFormat, SEED_ENV_VAR},
+    immutable_list::{ImmutableListNode, take_printed_values},
+    interactive::{
+        MOUNTAIN_ARRAY_CALL_LIMIT, MountainArray, VersionControl, guess, judge_call_count,
+        set_judge_call_limit, set_secret,
+    },
     list::{ListHead, ListNode},
+    nary_tree::{NaryTreeRoot, Node},
+    nested_integer::NestedInteger,
     tree::{TreeNode, TreeRoot},
+    validators::{is_permutation_of, is_sorted_subsequence_of, is_valid_path_in_grid},
 };
 
 #[cfg(feature = "tool")]
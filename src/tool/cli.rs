@@ -1,4 +1,4 @@
-use std::env;
+use std::{env, path::PathBuf};
 
 use anyhow::Context;
 use clap::{Args, Parser, Subcommand, ValueEnum};
@@ -67,15 +67,41 @@ pub enum Commands {
     Active(ActiveArgs),
     /// Run tests on active problem
     Test,
+    /// Prints the statement of the active problem or the one given
+    Show(ShowArgs),
     /// Creates a new pre-configured project from a template for use with
     /// cargo-leet
     New(NewArgs),
+    /// Generates modules from your accepted rust submissions on leetcode for
+    /// problems not yet in the project.
+    /// Requires the `LEETCODE_SESSION` and `LEETCODE_CSRF_TOKEN` environment
+    /// variables to be set from a logged in browser session
+    SyncSubmissions,
+    /// Regenerates the code below the separator (tests and support code) of
+    /// every module in `src/lib.rs`, showing a diff and asking for confirmation
+    /// before changing each file
+    Upgrade,
+    /// Renames existing modules to match the naming preference in
+    /// `.leet.toml` (`module_name_pattern`), updating their declarations
+    RenameModules,
 }
 
 #[derive(Args, Debug)]
+#[allow(clippy::struct_excessive_bools)] // Each bool is an independent command line flag
 pub struct GenerateArgs {
-    /// Question slug or url (If none specified then daily challenge is used)
-    pub problem: Option<String>,
+    /// Question slugs, urls or numbers (If none specified then daily challenge
+    /// is used)
+    pub problems: Vec<String>,
+
+    /// File with one question slug, url or number per line (Blank lines and
+    /// lines starting with `#` are ignored)
+    #[arg(long = "from-file", value_name = "FILE")]
+    pub from_file: Option<PathBuf>,
+
+    /// The problem to set as active when generating multiple problems
+    /// (Defaults to the last one generated successfully)
+    #[arg(long, value_name = "PROBLEM")]
+    pub active: Option<String>,
 
     /// If set the module name generated WILL include the number for the problem, overriding `.leet.toml`
     #[arg(
@@ -94,6 +120,28 @@ pub struct GenerateArgs {
         default_value_t = false
     )]
     pub should_not_include_problem_number_in_mod_name: bool,
+
+    /// Only regenerate the code below the separator (tests and support code)
+    /// in an existing module, keeping the solution untouched
+    #[arg(long = "tests-only", group = "destination", default_value_t = false)]
+    pub tests_only: bool,
+
+    /// Print the module name and generated code to stdout without changing
+    /// any files
+    #[arg(long = "dry-run", group = "destination", default_value_t = false)]
+    pub dry_run: bool,
+
+    /// Write the generated code to this file instead of a module in the
+    /// project (Does not update `src/lib.rs` or `.leet.toml`)
+    #[arg(long, short, group = "destination", value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+impl GenerateArgs {
+    /// Returns true if the command does not need to be run from a project
+    pub const fn is_independent_of_project(&self) -> bool {
+        self.dry_run || self.output.is_some()
+    }
 }
 
 #[derive(Args, Debug)]
@@ -101,6 +149,13 @@ pub struct ActiveArgs {
     pub problem_slug: Option<String>,
 }
 
+#[derive(Args, Debug)]
+pub struct ShowArgs {
+    /// Question slug, url or number (If not specified then the active problem
+    /// is used)
+    pub problem: Option<String>,
+}
+
 #[derive(Args, Debug)]
 pub struct NewArgs {
     /// Name of the new project
@@ -4,6 +4,15 @@ impl Config {
     // assumed in the code using them URLs Must end with trailing "/"
     pub(crate) const LEETCODE_PROBLEM_URL: &'static str = "https://leetcode.com/problems/";
     pub(crate) const LEETCODE_GRAPH_QL: &'static str = "https://leetcode.com/graphql/";
+    pub(crate) const LEETCODE_SUBMISSIONS_API: &'static str =
+        "https://leetcode.com/api/submissions/";
+
+    // Names of the environment variables used to authenticate with leetcode
+    pub(crate) const ENV_VAR_SESSION: &'static str = "LEETCODE_SESSION";
+    pub(crate) const ENV_VAR_CSRF_TOKEN: &'static str = "LEETCODE_CSRF_TOKEN";
+
+    /// Time to wait between problems when sending many requests in a row
+    pub(crate) const MILLISECONDS_DELAY_BETWEEN_PROBLEMS: u64 = 2000;
 }
 
 #[cfg(test)]
@@ -20,4 +29,9 @@ mod tests {
     fn graph_ql_url_ends_with_slash() {
         assert!(Config::LEETCODE_GRAPH_QL.ends_with('/'));
     }
+
+    #[test]
+    fn submissions_api_url_ends_with_slash() {
+        assert!(Config::LEETCODE_SUBMISSIONS_API.ends_with('/'));
+    }
 }
@@ -1,4 +1,4 @@
-use std::fs;
+use std::{collections::BTreeMap, fs};
 
 use anyhow::Context;
 use log::info;
@@ -6,9 +6,75 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Serialize, Deserialize, Debug, Default)]
 #[serde(default, deny_unknown_fields)]
+#[allow(clippy::struct_excessive_bools)] // each is an independent setting in the file
 pub(crate) struct ConfigFile {
     pub active: Option<String>,
     pub should_include_problem_number_in_mod_name: bool,
+    /// Pattern for module names (e.g. `p{id:04}_{slug}`), takes precedence over
+    /// `should_include_problem_number_in_mod_name`
+    pub module_name_pattern: Option<String>,
+    /// Add the problem description as documentation at the top of the module
+    pub should_include_description_in_docs: bool,
+    /// Add an ignored test that calls the solution with the largest inputs
+    /// allowed by the constraints in the description
+    pub should_include_stress_test: bool,
+    /// Add a `brute_force` stub and an ignored test comparing it with the
+    /// solution on random inputs
+    pub should_include_fuzz_test: bool,
+    pub test_style: TestStyle,
+    pub layout: Layout,
+    /// How outputs are compared for specific problems (by slug) instead of
+    /// detecting it from the description
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub output_order: BTreeMap<String, OutputOrder>,
+}
+
+/// Style of the tests generated for the examples of a problem
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum TestStyle {
+    /// One `rstest` test with a `#[case]` per example (requires `rstest` as a
+    /// dev-dependency)
+    #[default]
+    Rstest,
+    /// One plain `#[test]` function per example
+    Plain,
+    /// One plain `#[test]` function looping over a table of all examples
+    ///
+    /// The table is a `Vec` built in the test rather than a `const` as values
+    /// like `Vec` and `String` cannot be built in a constant
+    Table,
+}
+
+/// How generated modules are grouped into directories
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Layout {
+    /// All modules directly in `src` (e.g. `src/two_sum.rs`)
+    #[default]
+    Flat,
+    /// Grouped by difficulty (e.g. `src/easy/two_sum.rs`)
+    Difficulty,
+    /// Grouped by the first topic tag (e.g. `src/array/two_sum.rs`)
+    Topic,
+    /// Grouped by ranges of 100 problem numbers (e.g.
+    /// `src/p0001_0100/two_sum.rs`)
+    IdRange,
+}
+
+/// Whether the output of a problem must match the expected output exactly
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum OutputOrder {
+    /// Compared as is
+    #[default]
+    Exact,
+    /// Vecs are sorted before comparing, for `Vec<Vec<_>>` the inner vecs are
+    /// sorted too
+    Any,
+    /// Only the outer vec is sorted before comparing (e.g. permutations where
+    /// the order inside each answer matters)
+    AnyOuter,
 }
 
 impl ConfigFile {
@@ -1,23 +1,23 @@
-use std::path::Path;
-
 use anyhow::{Context, bail};
 
-use crate::tool::{cli, config_file::ConfigFile};
+use crate::tool::{
+    cli,
+    config_file::ConfigFile,
+    core::helpers::{existing_modules::list_existing_modules, module_path::ModulePath},
+};
 
 pub(crate) fn do_active(args: &cli::ActiveArgs) -> anyhow::Result<()> {
     let mut config = ConfigFile::load().unwrap_or_default();
     match &args.problem_slug {
         Some(slug) => {
-            if !Path::new(format!("src/{slug}.rs").as_str()).exists() {
-                bail!("problem {slug} does not exist");
-            }
             if slug == "lib.rs" {
                 bail!("cannot set active problem to lib.rs");
             }
+            let module_path = resolve_module_path(slug)?;
 
-            config.active = Some(slug.to_string());
+            config.active = Some(module_path.to_string());
             config.save().context("failed to save config")?;
-            println!("Set active problem to {slug}");
+            println!("Set active problem to {module_path}");
         }
         None => match &config.active {
             Some(active) => {
@@ -31,3 +31,34 @@ pub(crate) fn do_active(args: &cli::ActiveArgs) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Accepts either the full path of the module (e.g. `easy::two_sum`) or just
+/// its name if that is unambiguous
+fn resolve_module_path(problem: &str) -> anyhow::Result<ModulePath> {
+    let module_path: ModulePath = problem.parse()?;
+    if module_path.file_path().exists() {
+        return Ok(module_path);
+    }
+    if problem.contains("::") {
+        bail!("problem {problem} does not exist");
+    }
+
+    let mut matches: Vec<_> = list_existing_modules()
+        .context("failed to list existing modules")?
+        .into_iter()
+        .filter(|module| module.module_path.name() == problem && module.path.exists())
+        .map(|module| module.module_path)
+        .collect();
+    match matches.len() {
+        0 => bail!("problem {problem} does not exist"),
+        1 => Ok(matches.pop().expect("length checked above")),
+        _ => bail!(
+            "problem {problem} is ambiguous, use one of: {}",
+            matches
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
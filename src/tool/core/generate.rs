@@ -1,35 +1,98 @@
 use anyhow::{Context, bail};
-use convert_case::{Case, Casing};
-use log::info;
-use std::borrow::Cow;
-use std::fmt::Write;
+use log::{debug, info, warn};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    fmt::Write as _,
+    fs,
+    path::Path,
+};
 
 use crate::tool::{
     cli,
     config::Config,
-    config_file::ConfigFile,
+    config_file::{ConfigFile, Layout, OutputOrder, TestStyle},
     core::helpers::{
-        code_snippet::get_code_snippet_for_problem, daily_challenge,
-        problem_description::get_problem_description, problem_metadata::get_problem_metadata,
-        write_to_disk,
+        self,
+        checker::CheckerConfig,
+        code_snippet::get_code_snippet_for_problem,
+        daily_challenge, existing_modules,
+        module_name::{PATTERN_WITH_NUMBER, PATTERN_WITHOUT_NUMBER, module_name},
+        module_path::ModulePath,
+        problem_description::get_problem_description,
+        problem_id::get_slug_from_problem_id,
+        problem_metadata::get_problem_metadata,
+        template, write_to_disk,
     },
 };
 
 pub(crate) const SEPARATOR: &str =
     "// << ---------------- Code below here is only for local use ---------------- >>";
 
+/// Path (relative to the project root) of the optional user provided template
+/// used to lay out generated modules
+const MODULE_TEMPLATE_PATH: &str = ".leet/template.rs.tmpl";
+
+/// Layout used for generated modules if the user does not provide a template
+/// See [`template`] for the syntax
+const DEFAULT_MODULE_TEMPLATE: &str = "//! Solution for {{url}}
+//! {{id}}. {{title}}
+{{description_docs}}
+{{snippet}}
+
+{{separator}}
+
+{{solution_struct}}{{imports}}{{tests}}";
+
+/// Preferences that control how a module is generated
+#[derive(Debug, Default, Clone)]
+#[allow(clippy::struct_excessive_bools)] // mirrors the settings in the config file
+pub(crate) struct ModuleOptions {
+    pub(crate) should_include_problem_number_in_mod_name: bool,
+    /// Takes precedence over `should_include_problem_number_in_mod_name` if set
+    pub(crate) module_name_pattern: Option<String>,
+    pub(crate) should_include_description_in_docs: bool,
+    pub(crate) should_include_stress_test: bool,
+    pub(crate) should_include_fuzz_test: bool,
+    pub(crate) test_style: TestStyle,
+    pub(crate) layout: Layout,
+    /// Overrides the output order detected from the description (by slug)
+    pub(crate) output_order: BTreeMap<String, OutputOrder>,
+}
+
+impl ModuleOptions {
+    /// Pattern used to build module names (see [`module_name`])
+    pub(crate) fn module_name_pattern(&self) -> &str {
+        self.module_name_pattern.as_deref().unwrap_or(
+            if self.should_include_problem_number_in_mod_name {
+                PATTERN_WITH_NUMBER
+            } else {
+                PATTERN_WITHOUT_NUMBER
+            },
+        )
+    }
+}
+
+impl From<&ConfigFile> for ModuleOptions {
+    fn from(config: &ConfigFile) -> Self {
+        Self {
+            should_include_problem_number_in_mod_name: config
+                .should_include_problem_number_in_mod_name,
+            module_name_pattern: config.module_name_pattern.clone(),
+            should_include_description_in_docs: config.should_include_description_in_docs,
+            should_include_stress_test: config.should_include_stress_test,
+            should_include_fuzz_test: config.should_include_fuzz_test,
+            test_style: config.test_style,
+            layout: config.layout,
+            output_order: config.output_order.clone(),
+        }
+    }
+}
+
 pub(crate) fn do_generate(args: &cli::GenerateArgs) -> anyhow::Result<()> {
     let mut config = ConfigFile::load().context("failed to load config")?;
 
-    let title_slug: Cow<str> = if let Some(specific_problem) = &args.problem {
-        get_slug_from_args(specific_problem)
-            .with_context(|| format!("expected URL or slug but got {specific_problem}"))?
-    } else {
-        // Daily problem
-        let slug = daily_challenge::get_daily_challenge_slug()?;
-        info!("Slug for daily problem is: '{slug}'");
-        Cow::Owned(slug)
-    };
+    let problems = get_requested_problems(args)?;
 
     assert!(
         !(args.should_include_problem_number_in_mod_name
@@ -37,55 +100,242 @@ pub(crate) fn do_generate(args: &cli::GenerateArgs) -> anyhow::Result<()> {
         "Both 'should include' and 'should not include' are true. Impossible to satisfy, was intended to be prevented by Clap group"
     );
 
-    let should_include_problem_number_in_mod_name =
-        if args.should_include_problem_number_in_mod_name {
-            // User wants problem number so this takes precedence
-            true
-        } else if args.should_not_include_problem_number_in_mod_name {
-            // User does not want problem number so this takes precedence
-            false
+    let mut options = ModuleOptions::from(&config);
+    if args.should_include_problem_number_in_mod_name {
+        // User wants problem number so this takes precedence
+        options.should_include_problem_number_in_mod_name = true;
+        options.module_name_pattern = None;
+    } else if args.should_not_include_problem_number_in_mod_name {
+        // User does not want problem number so this takes precedence
+        options.should_include_problem_number_in_mod_name = false;
+        options.module_name_pattern = None;
+    }
+
+    let active_slug = match &args.active {
+        Some(problem) => {
+            let slug = get_slug_from_args(problem)
+                .with_context(|| format!("expected URL, slug or number but got {problem}"))?;
+            Some(slug)
+        }
+        None => None,
+    };
+
+    let destination = if args.tests_only {
+        Destination::TestsOnly
+    } else if args.dry_run {
+        Destination::Stdout
+    } else if let Some(path) = &args.output {
+        if problems.len() > 1 {
+            bail!("only one problem can be generated when an output file is specified");
+        }
+        Destination::File(path)
+    } else {
+        Destination::Module
+    };
+
+    // Each problem is resolved in the loop so one that cannot be found is
+    // reported in the summary instead of stopping the others
+    let mut outcomes: Vec<(String, anyhow::Result<String>)> = Vec::with_capacity(problems.len());
+    for (i, problem) in problems.iter().enumerate() {
+        helpers::delay_between_problems(i);
+        let (title_slug, outcome) = match get_slug_from_args(problem)
+            .with_context(|| format!("expected URL, slug or number but got {problem}"))
+        {
+            Ok(title_slug) => {
+                if outcomes.iter().any(|(slug, _)| *slug == title_slug) {
+                    info!("Skipping duplicate problem {title_slug:?}");
+                    continue;
+                }
+                let outcome = generate_problem(&title_slug, &options, &destination);
+                (title_slug, outcome)
+            }
+            Err(e) => (problem.clone(), Err(e)),
+        };
+        // With only one problem there is no summary so report the error directly
+        let outcome = if problems.len() == 1 {
+            Ok(outcome?)
         } else {
-            // Use default from loaded preferences
-            config.should_include_problem_number_in_mod_name
+            outcome
         };
+        outcomes.push((title_slug, outcome));
+    }
 
-    let (module_name, module_code) =
-        create_module_code(&title_slug, should_include_problem_number_in_mod_name).with_context(
-            || format!("failed to generate the name and module code for {title_slug:?}"),
-        )?;
-    write_to_disk::write_file(&module_name, &module_code).context("failed to write to disk")?;
-    println!("Generated module: {module_name}");
+    if destination.is_new_module() {
+        let active_module = outcomes
+            .iter()
+            .rev()
+            .filter(|(title_slug, _)| active_slug.as_ref().is_none_or(|slug| slug == title_slug))
+            .find_map(|(_, outcome)| outcome.as_ref().ok());
+        if let Some(module_name) = active_module {
+            config.active = Some(module_name.clone());
+            config.save().context("failed to save config")?;
+        } else if let Some(slug) = active_slug
+            .as_ref()
+            .filter(|slug| !outcomes.iter().any(|(title_slug, _)| title_slug == *slug))
+        {
+            warn!("Active problem not updated as {slug:?} is not one of those requested");
+        } else {
+            warn!("Active problem not updated as the requested problem was not generated");
+        }
+    }
 
-    config.active = Some(module_name);
-    config.save().context("failed to save config")?;
+    if outcomes.len() > 1 {
+        print_summary(&outcomes);
+        let failed_count = outcomes
+            .iter()
+            .filter(|(_, outcome)| outcome.is_err())
+            .count();
+        if failed_count > 0 {
+            bail!(
+                "failed to generate {failed_count} of {} problems",
+                outcomes.len()
+            );
+        }
+    }
 
     Ok(())
 }
 
-fn get_slug_from_args(specific_problem: &String) -> anyhow::Result<Cow<'_, str>> {
+/// Returns the problems requested (URL, slug or number) without duplicates, or
+/// the slug of the daily challenge if none were requested
+fn get_requested_problems(args: &cli::GenerateArgs) -> anyhow::Result<Vec<String>> {
+    let mut problems = args.problems.clone();
+    if let Some(path) = &args.from_file {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("failed to read problems from {path:?}"))?;
+        problems.extend(
+            content
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(ToString::to_string),
+        );
+    }
+
+    if problems.is_empty() {
+        if let Some(path) = &args.from_file {
+            bail!("no problems found in {path:?}");
+        }
+        // Daily problem
+        let slug = daily_challenge::get_daily_challenge_slug()?;
+        info!("Slug for daily problem is: '{slug}'");
+        return Ok(vec![slug]);
+    }
+
+    let mut result: Vec<String> = Vec::with_capacity(problems.len());
+    for problem in problems {
+        if result.contains(&problem) {
+            info!("Skipping duplicate problem {problem:?}");
+        } else {
+            result.push(problem);
+        }
+    }
+    Ok(result)
+}
+
+/// Where the generated code for a problem is sent
+enum Destination<'a> {
+    /// New module in the project
+    Module,
+    /// Replace only the generated section of an existing module
+    TestsOnly,
+    /// Print without changing any files
+    Stdout,
+    /// Arbitrary file outside of the module structure
+    File(&'a Path),
+}
+
+impl Destination<'_> {
+    const fn is_new_module(&self) -> bool {
+        matches!(self, Self::Module)
+    }
+}
+
+/// Generates the module for a single problem and returns the path of the
+/// module (e.g. `easy::two_sum`)
+fn generate_problem(
+    title_slug: &str,
+    options: &ModuleOptions,
+    destination: &Destination,
+) -> anyhow::Result<String> {
+    let (module_path, module_code) =
+        create_module_code(title_slug, options, None).with_context(|| {
+            format!("failed to generate the name and module code for {title_slug:?}")
+        })?;
+    match destination {
+        Destination::Module => {
+            write_to_disk::write_file(&module_path, &module_code)
+                .context("failed to write to disk")?;
+            println!("Generated module: {module_path}");
+        }
+        Destination::TestsOnly => {
+            write_to_disk::write_generated_section(&module_path, &module_code)
+                .context("failed to update generated section")?;
+            println!("Regenerated tests for module: {module_path}");
+        }
+        Destination::Stdout => {
+            println!("// Module path: {module_path}\n{module_code}");
+        }
+        Destination::File(path) => {
+            write_to_disk::write_standalone_file(path, &module_code)
+                .with_context(|| format!("failed to write to {path:?}"))?;
+            println!("Generated {module_path} in {path:?}");
+        }
+    }
+    Ok(module_path.to_string())
+}
+
+fn print_summary(outcomes: &[(String, anyhow::Result<String>)]) {
+    let width = outcomes
+        .iter()
+        .map(|(title_slug, _)| title_slug.len())
+        .max()
+        .unwrap_or_default()
+        .max("Problem".len());
+    println!("\n{:<width$}  Result", "Problem");
+    for (title_slug, outcome) in outcomes {
+        let result = match outcome {
+            Ok(module_name) => format!("OK ({module_name})"),
+            Err(e) => format!("FAILED: {e:#}"),
+        };
+        println!("{title_slug:<width$}  {result}");
+    }
+}
+
+pub(crate) fn get_slug_from_args(specific_problem: &str) -> anyhow::Result<String> {
     Ok(if is_url(specific_problem) {
         // Working with a url
         info!("Using '{specific_problem}' as a url");
         let slug = url_to_slug(specific_problem)?;
         info!("Extracted slug '{slug}' from url");
-        Cow::Owned(slug)
+        slug
+    } else if let Ok(id) = specific_problem.parse::<u16>() {
+        // Working with a problem number
+        info!("Using '{specific_problem}' as a problem number");
+        let slug = get_slug_from_problem_id(id)?;
+        info!("Found slug '{slug}' for problem number");
+        slug
     } else {
         // This is expected to be a valid slug
         info!("Using '{specific_problem}' as a slug");
-        Cow::Borrowed(specific_problem)
+        specific_problem.to_string()
     })
 }
 
 /// Gets the code and other data from leetcode and generates the suitable code
-/// for the module and the name of the module Returns the module name and the
-/// module code
+/// for the module and the path of the module (based on
+/// [`ModuleOptions::layout`]) Returns the module path and the module code
 ///
-/// NB: Did not return `Cow` because `module_name` is always a modified version
-/// of the input
-fn create_module_code(
+/// If `submitted_code` is provided it is used in place of the code snippet from
+/// leetcode (for example a previously accepted submission)
+///
+/// If the module already exists the checker selected in it (see
+/// [`CheckerConfig`]) is used for the tests
+pub(crate) fn create_module_code(
     title_slug: &str,
-    should_include_problem_number_in_mod_name: bool,
-) -> anyhow::Result<(String, String)> {
+    options: &ModuleOptions,
+    submitted_code: Option<&str>,
+) -> anyhow::Result<(ModulePath, String)> {
     info!("Building module contents for {title_slug}");
 
     let meta_data =
@@ -94,56 +344,134 @@ fn create_module_code(
     let description =
         get_problem_description(title_slug).context("failed to retrieve problem description")?;
 
-    // Add problem URL
-    let mut code_snippet = format!(
-        "//! Solution for {}{title_slug}\n",
-        Config::LEETCODE_PROBLEM_URL
-    );
-
-    // Add problem number and title
-    writeln!(code_snippet, "//! {}", meta_data.get_num_and_title()).expect("write! macro failed");
-
-    // Add blank line between docstring and code
-    code_snippet.push('\n');
+    let module_name = module_name(options.module_name_pattern(), &meta_data, title_slug)
+        .context("failed to build module name")?;
+    let module_path = ModulePath::new(options.layout, &meta_data, module_name);
 
     // Get code snippet
-    let problem_code = get_code_snippet_for_problem(title_slug)?;
-    code_snippet.push_str(problem_code.as_ref());
-
-    writeln!(code_snippet, "\n\n{SEPARATOR}").expect("write! macro failed");
+    let mut problem_code = if let Some(code) = submitted_code {
+        info!("Using submitted code instead of the code snippet");
+        code.to_string()
+            .try_into()
+            .context("failed to parse submitted code")?
+    } else {
+        get_code_snippet_for_problem(title_slug)?
+    };
 
     // Add struct for non design questions
-    if problem_code.type_.is_non_design() {
-        code_snippet.push_str("\npub struct Solution;\n");
-    }
+    let solution_struct = if problem_code.type_.is_non_design() {
+        "pub struct Solution;\n"
+    } else {
+        ""
+    };
 
     // Add leet code types
+    let mut imports = String::new();
     if problem_code.has_tree() {
-        code_snippet.push_str("use cargo_leet::TreeNode;\n");
+        imports.push_str("use cargo_leet::TreeNode;\n");
     }
     if problem_code.has_list() {
-        code_snippet.push_str("use cargo_leet::ListNode;\n");
+        imports.push_str("use cargo_leet::ListNode;\n");
+    }
+    for name in problem_code.get_cargo_leet_imports() {
+        writeln!(imports, "use cargo_leet::{name};").expect("write! macro failed");
     }
 
     // Add tests
-    let tests = meta_data.get_test_cases(&problem_code, &description);
-    code_snippet.push_str(&tests);
+    problem_code.add_constraints(&description.get_constraints());
+    let output_order = options
+        .output_order
+        .get(title_slug)
+        .copied()
+        .unwrap_or_else(|| {
+            if description.is_any_order_accepted() {
+                info!("Description accepts the answer in any order");
+                OutputOrder::Any
+            } else {
+                OutputOrder::Exact
+            }
+        });
+    problem_code.set_output_order(output_order);
+    if let Some(checker) = read_checker_config(&module_path)? {
+        info!("Using checker {checker:?} selected in the module");
+        problem_code.set_checker(checker);
+    }
+    let tests = meta_data.get_test_cases(
+        &problem_code,
+        &description,
+        options.test_style,
+        options.should_include_stress_test,
+        options.should_include_fuzz_test,
+    );
 
-    // Set module name
-    let mut module_name = if should_include_problem_number_in_mod_name {
-        info!("Including problem number in module name");
-        format!("_{}_{}", meta_data.id, title_slug.to_case(Case::Snake))
+    let description_markdown = description.to_markdown();
+    let description_docs = if options.should_include_description_in_docs {
+        format!("//!\n{}\n", template::doc_comment(&description_markdown))
     } else {
-        info!("Using snake case slug for module name");
-        title_slug.to_case(Case::Snake)
+        String::new()
     };
 
-    // Ensure module name does not start with a leading number
-    if module_name.starts_with(|first_char: char| first_char.is_ascii_digit()) {
-        module_name = format!("_{module_name}");
+    let values = HashMap::from([
+        (
+            "url",
+            format!("{}{title_slug}", Config::LEETCODE_PROBLEM_URL),
+        ),
+        ("slug", title_slug.to_string()),
+        ("id", meta_data.id.to_string()),
+        ("title", meta_data.title.clone()),
+        (
+            "difficulty",
+            meta_data.difficulty.clone().unwrap_or_default(),
+        ),
+        ("tags", meta_data.topic_tags.join(", ")),
+        ("description", description_markdown),
+        ("description_docs", description_docs),
+        ("snippet", problem_code.as_ref().to_string()),
+        ("separator", SEPARATOR.to_string()),
+        ("solution_struct", solution_struct.to_string()),
+        ("imports", imports),
+        ("tests", tests),
+    ]);
+    let template = load_module_template()?;
+    let code_snippet =
+        template::render(&template, &values).context("failed to render module template")?;
+    if !code_snippet.contains(SEPARATOR) {
+        warn!(
+            "Module template does not include {{{{separator}}}}, regenerating tests will not work for this module"
+        );
+    }
+    if !code_snippet.starts_with(&existing_modules::header_prefix()) {
+        warn!(
+            "Module template does not start with \"//! Solution for {{{{url}}}}\", the problem of this module will not be found by other commands"
+        );
     }
 
-    Ok((module_name, code_snippet))
+    Ok((module_path, code_snippet))
+}
+
+/// Checker selected in the existing module at `module_path`, `None` if the
+/// module does not exist or does not select one
+fn read_checker_config(module_path: &ModulePath) -> anyhow::Result<Option<CheckerConfig>> {
+    let path = module_path.file_path();
+    if !path.exists() {
+        return Ok(None);
+    }
+    let existing = fs::read_to_string(&path)
+        .with_context(|| format!("failed to read existing module {path:?}"))?;
+    CheckerConfig::from_module_code(&existing)
+        .with_context(|| format!("failed to read checker selected in {path:?}"))
+}
+
+fn load_module_template() -> anyhow::Result<Cow<'static, str>> {
+    let path = Path::new(MODULE_TEMPLATE_PATH);
+    if !path.exists() {
+        debug!("No module template found at {path:?}. Using default");
+        return Ok(Cow::Borrowed(DEFAULT_MODULE_TEMPLATE));
+    }
+    info!("Using module template from {path:?}");
+    fs::read_to_string(path)
+        .map(Cow::Owned)
+        .with_context(|| format!("failed to read module template from {path:?}"))
 }
 
 /// Quick and dirty test to see if this is a url
@@ -179,7 +507,7 @@ mod tests {
     fn slug_in_slug_out() {
         let slug = "two-sum".to_string();
         let actual = get_slug_from_args(&slug).expect("Expect value to be valid");
-        assert_eq!(actual.to_string(), slug);
+        assert_eq!(actual, slug);
     }
 
     #[test]
@@ -187,7 +515,15 @@ mod tests {
         let url = "https://leetcode.com/problems/two-sum/".to_string();
         let expected = "two-sum";
         let actual = get_slug_from_args(&url).expect("Expect value to be valid");
-        assert_eq!(actual.to_string(), expected);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn number_in_slug_out() {
+        let number = "20".to_string();
+        let expected = "valid-parentheses";
+        let actual = get_slug_from_args(&number).expect("Expect value to be valid");
+        assert_eq!(actual, expected);
     }
 
     #[test]
@@ -202,9 +538,117 @@ mod tests {
     fn extract_solutions_from_description(title_slugs: SlugList, insta_settings: insta::Settings) {
         for title_slug in title_slugs {
             insta_settings.bind(|| {
-                let (_, code_generated) = create_module_code(title_slug, false).unwrap();
+                let (_, code_generated) =
+                    create_module_code(title_slug, &ModuleOptions::default(), None).unwrap();
                 insta::assert_snapshot!(format!("code_generated {title_slug}"), code_generated);
             });
         }
     }
+
+    #[rstest]
+    fn code_generated_with_description_docs(insta_settings: insta::Settings) {
+        let options = ModuleOptions {
+            should_include_description_in_docs: true,
+            ..Default::default()
+        };
+        let (_, code_generated) = create_module_code("two-sum", &options, None).unwrap();
+        insta_settings.bind(|| {
+            insta::assert_snapshot!("code_generated_with_description_docs", code_generated);
+        });
+    }
+
+    #[rstest]
+    fn code_generated_with_stress_test(
+        #[values("two-sum", "add-two-numbers", "valid-parentheses", "flood-fill")] title_slug: &str,
+        insta_settings: insta::Settings,
+    ) {
+        let options = ModuleOptions {
+            should_include_stress_test: true,
+            ..Default::default()
+        };
+        let (_, code_generated) = create_module_code(title_slug, &options, None).unwrap();
+        insta_settings.bind(|| {
+            insta::assert_snapshot!(
+                format!("code_generated_with_stress_test {title_slug}"),
+                code_generated
+            );
+        });
+    }
+
+    #[rstest]
+    fn code_generated_with_fuzz_test(
+        #[values(
+            "two-sum",
+            "add-two-numbers",
+            "valid-parentheses",
+            "flood-fill",
+            "validate-binary-search-tree"
+        )]
+        title_slug: &str,
+        insta_settings: insta::Settings,
+    ) {
+        let options = ModuleOptions {
+            should_include_fuzz_test: true,
+            ..Default::default()
+        };
+        let (_, code_generated) = create_module_code(title_slug, &options, None).unwrap();
+        insta_settings.bind(|| {
+            insta::assert_snapshot!(
+                format!("code_generated_with_fuzz_test {title_slug}"),
+                code_generated
+            );
+        });
+    }
+
+    #[rstest]
+    fn code_generated_output_order(
+        #[values(OutputOrder::Exact, OutputOrder::AnyOuter)] output_order: OutputOrder,
+        insta_settings: insta::Settings,
+    ) {
+        // The description of two-sum says the answer can be in any order
+        let options = ModuleOptions {
+            output_order: BTreeMap::from([("two-sum".to_string(), output_order)]),
+            ..Default::default()
+        };
+        let (_, code_generated) = create_module_code("two-sum", &options, None).unwrap();
+        insta_settings.bind(|| {
+            insta::assert_snapshot!(
+                format!("code_generated_output_order {output_order:?}"),
+                code_generated
+            );
+        });
+    }
+
+    #[rstest]
+    fn code_generated_test_styles(
+        #[values(TestStyle::Plain, TestStyle::Table)] test_style: TestStyle,
+        #[values("two-sum", "add-two-numbers", "valid-parentheses")] title_slug: &str,
+        insta_settings: insta::Settings,
+    ) {
+        let options = ModuleOptions {
+            test_style,
+            ..Default::default()
+        };
+        let (_, code_generated) = create_module_code(title_slug, &options, None).unwrap();
+        insta_settings.bind(|| {
+            insta::assert_snapshot!(
+                format!("code_generated {test_style:?} {title_slug}"),
+                code_generated
+            );
+        });
+    }
+
+    #[rstest]
+    fn code_generated_from_submission(insta_settings: insta::Settings) {
+        let submitted_code = "impl Solution {
+    pub fn two_sum(nums: Vec<i32>, target: i32) -> Vec<i32> {
+        vec![0, 1]
+    }
+}";
+        let (_, code_generated) =
+            create_module_code("two-sum", &ModuleOptions::default(), Some(submitted_code)).unwrap();
+        insta_settings.bind(|| {
+            insta::assert_snapshot!(code_generated);
+        });
+    }
 }
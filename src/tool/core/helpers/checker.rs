@@ -0,0 +1,126 @@
+//! Checker selected in a module for problems that accept more than one answer
+//!
+//! Selected with a comment above the separator of the module (so it is kept
+//! when the tests are regenerated), for example:
+//!
+//! ```text
+//! // cargo-leet: checker = is_permutation_of
+//! ```
+
+use anyhow::bail;
+
+use crate::tool::core::generate::SEPARATOR;
+
+const CONFIG_COMMENT_PREFIX: &str = "// cargo-leet: checker =";
+
+/// Name of the function generated to check answers
+pub(crate) const CHECK_FN_NAME: &str = "check_answer";
+
+/// How the generated `check_answer` function checks the answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Checker {
+    /// Stub for the user to fill in
+    Custom,
+    /// Same items as the expected answer in any order
+    IsPermutationOf,
+    /// Sorted subsequence of the first argument of the same type with the
+    /// length of the expected answer (e.g. any longest increasing
+    /// subsequence)
+    IsSortedSubsequenceOf,
+    /// Path of adjacent cells in the first grid argument with the length of
+    /// the expected answer (e.g. any shortest path)
+    IsValidPathInGrid,
+}
+
+impl Checker {
+    const ALL: [Self; 4] = [
+        Self::Custom,
+        Self::IsPermutationOf,
+        Self::IsSortedSubsequenceOf,
+        Self::IsValidPathInGrid,
+    ];
+
+    /// Name used in the config comment, also the name of the `cargo_leet`
+    /// validator except for [`Self::Custom`]
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Self::Custom => "custom",
+            Self::IsPermutationOf => "is_permutation_of",
+            Self::IsSortedSubsequenceOf => "is_sorted_subsequence_of",
+            Self::IsValidPathInGrid => "is_valid_path_in_grid",
+        }
+    }
+}
+
+/// Checker selected for a module
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct CheckerConfig {
+    pub(crate) checker: Checker,
+    /// `check_answer` is written above the separator so none is generated
+    pub(crate) is_user_defined: bool,
+}
+
+impl CheckerConfig {
+    /// Reads the config comment in the part of `module_code` above the
+    /// separator, `None` if there is none
+    pub(crate) fn from_module_code(module_code: &str) -> anyhow::Result<Option<Self>> {
+        let user_code = module_code
+            .find(SEPARATOR)
+            .map_or(module_code, |i| &module_code[..i]);
+        let Some(name) = user_code
+            .lines()
+            .find_map(|line| line.trim().strip_prefix(CONFIG_COMMENT_PREFIX))
+            .map(str::trim)
+        else {
+            return Ok(None);
+        };
+        let Some(checker) = Checker::ALL
+            .into_iter()
+            .find(|checker| checker.name() == name)
+        else {
+            let names: Vec<_> = Checker::ALL.into_iter().map(Checker::name).collect();
+            bail!(
+                "unknown checker {name:?} in module, expected one of: {}",
+                names.join(", ")
+            );
+        };
+        Ok(Some(Self {
+            checker,
+            is_user_defined: user_code.contains(&format!("fn {CHECK_FN_NAME}")),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::no_comment("impl Solution {}", None)]
+    #[case::comment(
+        "// cargo-leet: checker = is_permutation_of\nimpl Solution {}",
+        Some(CheckerConfig { checker: Checker::IsPermutationOf, is_user_defined: false })
+    )]
+    #[case::user_defined(
+        "impl Solution {}\n  // cargo-leet: checker = custom\nfn check_answer() -> bool { true }",
+        Some(CheckerConfig { checker: Checker::Custom, is_user_defined: true })
+    )]
+    #[case::only_above_separator(
+        &format!("impl Solution {{}}\n{SEPARATOR}\n// cargo-leet: checker = custom\nfn check_answer() {{}}"),
+        None
+    )]
+    fn from_module_code(#[case] code: &str, #[case] expected: Option<CheckerConfig>) {
+        assert_eq!(CheckerConfig::from_module_code(code).unwrap(), expected);
+    }
+
+    #[test]
+    fn unknown_checker() {
+        let err = CheckerConfig::from_module_code("// cargo-leet: checker = any").unwrap_err();
+        assert!(
+            err.to_string().contains("custom, is_permutation_of"),
+            "{err}"
+        );
+    }
+}
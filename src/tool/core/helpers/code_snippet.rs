@@ -1,4 +1,7 @@
-use super::{get_response, local_store::path_local_store_code_snippet, problem_code::ProblemCode};
+use super::{
+    get_response, local_store::path_local_store_code_snippet, problem_category::ProblemCategory,
+    problem_code::ProblemCode,
+};
 use crate::tool::config::Config;
 use anyhow::{Context, bail};
 use log::info;
@@ -12,12 +15,16 @@ struct CodeSnippetResponse {
 }
 impl CodeSnippetResponse {
     fn into_rust_problem_code(self) -> anyhow::Result<ProblemCode> {
-        let code_snippet = self.data.question.code_snippets;
-        let Some(mut result) = code_snippet
+        let Question {
+            code_snippets,
+            category,
+        } = self.data.question;
+        info!("Problem category is {category:?}");
+        let Some(mut result) = code_snippets
             .into_iter()
             .find_map(|cs| (cs.lang == "Rust").then_some(cs.code))
         else {
-            bail!("Rust not supported for this problem")
+            bail!("{}", category.no_rust_message())
         };
 
         // Add todo!() placeholders in function bodies
@@ -26,7 +33,7 @@ impl CodeSnippetResponse {
             .replace_all(&result, r#"{ todo!("Fill in body") }"#)
             .to_string();
 
-        result.try_into()
+        ProblemCode::new(result, category)
     }
 }
 
@@ -39,6 +46,9 @@ struct Data {
 struct Question {
     #[serde(rename = "codeSnippets")]
     code_snippets: CodeSnippets,
+    /// Not in responses stored before the category was requested
+    #[serde(rename = "categoryTitle", default)]
+    category: ProblemCategory,
 }
 
 #[derive(serde::Deserialize, Debug)]
@@ -71,6 +81,7 @@ fn external_request_code_snippet(title_slug: &str) -> anyhow::Result<String> {
         .send_json(serde_json::json!({
             "query": "query questionEditorData($titleSlug: String!) {
                     question(titleSlug: $titleSlug) {
+                        categoryTitle
                         codeSnippets {
                             lang
                             code
@@ -94,7 +105,9 @@ mod tests {
     use rstest::rstest;
 
     use crate::tool::core::helpers::{
-        code_snippet::{external_request_code_snippet, get_code_snippets_response},
+        code_snippet::{
+            CodeSnippetResponse, external_request_code_snippet, get_code_snippets_response,
+        },
         local_store::tests::{SlugList, get_rnd_request_delay, insta_settings, title_slugs},
     };
 
@@ -123,6 +136,20 @@ mod tests {
         println!("Successfully saved all responses");
     }
 
+    #[rstest]
+    #[case("Database", "database problem which is solved in SQL")]
+    #[case("Shell", "shell problem which is solved with a bash script")]
+    #[case("pandas", "pandas problem which is solved in Python")]
+    #[case("Algorithms", "Rust not supported for this problem")]
+    fn no_rust_snippet_message(#[case] category: &str, #[case] expected: &str) {
+        let json = format!(
+            r#"{{"data":{{"question":{{"categoryTitle":"{category}","codeSnippets":[{{"lang":"MySQL","code":""}}]}}}}}}"#
+        );
+        let response: CodeSnippetResponse = serde_json::from_str(&json).unwrap();
+        let err = response.into_rust_problem_code().unwrap_err();
+        assert!(err.to_string().contains(expected), "{err}");
+    }
+
     #[rstest]
     fn conversion_from_leetcode_response(title_slugs: SlugList, insta_settings: insta::Settings) {
         for title_slug in title_slugs {
@@ -0,0 +1,105 @@
+//! Discovery of the modules already generated in the project
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use log::{debug, warn};
+use regex::Regex;
+
+use crate::tool::{config::Config, core::helpers::module_path::ModulePath};
+
+/// Prefix of the first line of every generated module, followed by the slug
+pub(crate) fn header_prefix() -> String {
+    format!("//! Solution for {}", Config::LEETCODE_PROBLEM_URL)
+}
+
+#[derive(Debug)]
+pub(crate) struct ExistingModule {
+    pub(crate) module_path: ModulePath,
+    pub(crate) path: PathBuf,
+    /// Only available if the module has the header added during generation
+    pub(crate) title_slug: Option<String>,
+}
+
+/// Lists the modules declared in `src/lib.rs` including those nested in
+/// directories (declared in a `mod.rs`)
+pub(crate) fn list_existing_modules() -> anyhow::Result<Vec<ExistingModule>> {
+    let exp =
+        Regex::new(r"^\s*(?:pub )?mod ([a-zA-Z\-\_0-9]+);").context("failed to create regex")?;
+    let mut result = vec![];
+    collect_modules(Path::new("src/lib.rs"), None, &exp, &mut result)?;
+    Ok(result)
+}
+
+fn collect_modules(
+    declaring_file: &Path,
+    parent: Option<&ModulePath>,
+    exp: &Regex,
+    result: &mut Vec<ExistingModule>,
+) -> anyhow::Result<()> {
+    let contents = fs::read_to_string(declaring_file)
+        .with_context(|| format!("failed to read {declaring_file:?}"))?;
+    for line in contents.lines() {
+        let Some(caps) = exp.captures(line) else {
+            continue;
+        };
+        let name = caps[1].to_string();
+        let module_path = parent.map_or_else(
+            || ModulePath::top_level(name.clone()),
+            |parent| parent.child(name.clone()),
+        );
+        let path = module_path.file_path();
+        if !path.exists() {
+            let mod_rs_path = module_path.mod_rs_path();
+            if mod_rs_path.exists() {
+                debug!("Looking for modules inside of {module_path}");
+                collect_modules(&mod_rs_path, Some(&module_path), exp, result)?;
+                continue;
+            }
+        }
+        let title_slug = match fs::read_to_string(&path) {
+            Ok(code) => extract_title_slug(&code),
+            Err(e) => {
+                warn!("failed to read {path:?} to find its slug. Error: {e}");
+                None
+            }
+        };
+        debug!("Found existing module {module_path} with slug {title_slug:?}");
+        result.push(ExistingModule {
+            module_path,
+            path,
+            title_slug,
+        });
+    }
+    Ok(())
+}
+
+/// Extracts the slug from the header at the top of a generated module
+pub(crate) fn extract_title_slug(code: &str) -> Option<String> {
+    let first_line = code.lines().next()?;
+    let slug = first_line
+        .strip_prefix(&header_prefix())?
+        .trim()
+        .trim_end_matches('/');
+    (!slug.is_empty()).then(|| slug.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slug_from_header() {
+        let code = "//! Solution for https://leetcode.com/problems/two-sum\n//! 1. Two Sum\n";
+        assert_eq!(extract_title_slug(code).as_deref(), Some("two-sum"));
+    }
+
+    #[test]
+    fn slug_from_header_missing() {
+        let code = "//! Some other module\n";
+        assert_eq!(extract_title_slug(code), None);
+    }
+}
@@ -8,6 +8,8 @@ const NAME_LOCAL_STORE_CODE_SNIPPET: &str = "code_snippet";
 const NAME_LOCAL_STORE_DAILY_CHALLENGE: &str = "daily_challenge";
 const NAME_LOCAL_STORE_PROBLEM_METADATA: &str = "problem_metadata";
 const NAME_LOCAL_STORE_PROBLEM_DESCRIPTION: &str = "problem_description";
+const NAME_LOCAL_STORE_SUBMISSIONS: &str = "submissions";
+const NAME_LOCAL_STORE_PROBLEM_ID: &str = "problem_id";
 
 pub(crate) fn path_local_store_code_snippet<P: AsRef<Path>>(path: P) -> PathBuf {
     PathBuf::from(NAME_TEST_FOLDER)
@@ -36,6 +38,19 @@ pub(crate) fn path_local_store_problem_description<P: AsRef<Path>>(path: P) -> P
         .join(path)
 }
 
+pub(crate) fn path_local_store_submissions() -> PathBuf {
+    PathBuf::from(NAME_TEST_FOLDER)
+        .join(NAME_LOCAL_STORE)
+        .join(NAME_LOCAL_STORE_SUBMISSIONS)
+}
+
+pub(crate) fn path_local_store_problem_id<P: AsRef<Path>>(path: P) -> PathBuf {
+    PathBuf::from(NAME_TEST_FOLDER)
+        .join(NAME_LOCAL_STORE)
+        .join(NAME_LOCAL_STORE_PROBLEM_ID)
+        .join(path)
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use rand::{Rng, rng};
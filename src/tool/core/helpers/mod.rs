@@ -1,13 +1,46 @@
+use std::{cell::RefCell, collections::HashMap};
+
 use anyhow::Context as _;
+use log::info;
+
+use crate::tool::config::Config;
 
+pub(crate) mod checker;
 pub(crate) mod code_snippet;
 pub(crate) mod daily_challenge;
+pub(crate) mod existing_modules;
 pub(crate) mod local_store;
+pub(crate) mod module_name;
+pub(crate) mod module_path;
+pub(crate) mod problem_category;
 pub(crate) mod problem_code;
 pub(crate) mod problem_description;
+pub(crate) mod problem_id;
 pub(crate) mod problem_metadata;
+pub(crate) mod session;
+pub(crate) mod submissions;
+pub(crate) mod template;
 pub(crate) mod write_to_disk;
 
+thread_local! {
+    /// Responses already received in this invocation, keyed by the type they
+    /// are parsed into and the slug (or id) requested so that generating
+    /// several problems does not request the same data twice
+    static RESPONSE_CACHE: RefCell<HashMap<(&'static str, String), String>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Waits before every request for a problem except the first (`i` is the index
+/// of the problem) so commands working on many problems don't go too hard on
+/// the leetcode API
+pub(crate) fn delay_between_problems(i: usize) {
+    if i > 0 {
+        std::thread::sleep(std::time::Duration::from_millis(
+            Config::MILLISECONDS_DELAY_BETWEEN_PROBLEMS,
+        ));
+    }
+}
+
 fn get_response<
     T: for<'de> serde::Deserialize<'de>,
     FLocal: FnOnce(&str) -> anyhow::Result<String>,
@@ -17,11 +50,66 @@ fn get_response<
     local_store_fn: FLocal,
     external_request_fn: FExternal,
 ) -> anyhow::Result<T> {
+    let key = (std::any::type_name::<T>(), title_slug.to_string());
+    let cached = RESPONSE_CACHE.with_borrow(|cache| cache.get(&key).cloned());
+    if let Some(json) = cached {
+        info!("Using cached response for {title_slug:?}");
+        return serde_json::from_str(&json).context("failed to convert from String as json");
+    }
     let json = if cfg!(test) {
         local_store_fn(title_slug)
     } else {
         external_request_fn(title_slug)
     }?;
     let result = serde_json::from_str(&json).context("failed to convert from String as json")?;
+    // Only cached once parsed so error pages are requested again
+    RESPONSE_CACHE.with_borrow_mut(|cache| cache.insert(key, json));
     Ok(result)
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::bail;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn response_cached() {
+        let first: Vec<i32> = get_response(
+            "cached-slug",
+            |_| Ok("[1, 2]".to_string()),
+            |_| bail!("no external requests in tests"),
+        )
+        .unwrap();
+        let second: Vec<i32> = get_response(
+            "cached-slug",
+            |_| bail!("expected the cached response to be used"),
+            |_| bail!("no external requests in tests"),
+        )
+        .unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[rstest]
+    #[case::request_failed("failing-slug", || bail!("request failed"))]
+    #[case::invalid_json("invalid-json-slug", || Ok("<html>Too Many Requests</html>".to_string()))]
+    fn failed_response_not_cached(
+        #[case] slug: &str,
+        #[case] first_response: fn() -> anyhow::Result<String>,
+    ) {
+        let first: anyhow::Result<Vec<i32>> = get_response(
+            slug,
+            |_| first_response(),
+            |_| bail!("no external requests in tests"),
+        );
+        assert!(first.is_err());
+        let second: Vec<i32> = get_response(
+            slug,
+            |_| Ok("[3]".to_string()),
+            |_| bail!("no external requests in tests"),
+        )
+        .unwrap();
+        assert_eq!(second, [3]);
+    }
+}
@@ -0,0 +1,155 @@
+//! Naming of generated modules based on a pattern
+//!
+//! Patterns are written with placeholders in braces for example
+//! `p{id:04}_{slug}`. Supported placeholders:
+//! - `id`: the problem number, optionally zero padded to a width (e.g.
+//!   `{id:04}`)
+//! - `slug`: the slug of the problem in snake case
+//! - `difficulty`: the difficulty of the problem in lowercase
+
+use anyhow::{Context, bail};
+use convert_case::{Case, Casing};
+
+use crate::tool::core::helpers::problem_metadata::data_structure::ProblemMetadata;
+
+/// Pattern used when the problem number is not included in the name
+pub(crate) const PATTERN_WITHOUT_NUMBER: &str = "{slug}";
+
+/// Pattern used when the problem number is included in the name
+pub(crate) const PATTERN_WITH_NUMBER: &str = "_{id}_{slug}";
+
+/// Reserved words that cannot be used as a module name
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Builds the name of the module for a problem from `pattern`
+///
+/// A leading `_` is added if the name would otherwise start with a number
+pub(crate) fn module_name(
+    pattern: &str,
+    metadata: &ProblemMetadata,
+    title_slug: &str,
+) -> anyhow::Result<String> {
+    let mut result = String::with_capacity(pattern.len() + title_slug.len());
+    let mut rest = pattern;
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        let after_open = &rest[start + 1..];
+        let Some(end) = after_open.find('}') else {
+            bail!("unterminated placeholder in module name pattern {pattern:?}");
+        };
+        let placeholder = &after_open[..end];
+        result.push_str(
+            &render_placeholder(placeholder, metadata, title_slug)
+                .with_context(|| format!("invalid module name pattern {pattern:?}"))?,
+        );
+        rest = &after_open[end + 1..];
+    }
+    result.push_str(rest);
+
+    // Ensure module name does not start with a leading number
+    if result.starts_with(|first_char: char| first_char.is_ascii_digit()) {
+        result = format!("_{result}");
+    }
+
+    validate_identifier(&result)
+        .with_context(|| format!("pattern {pattern:?} did not produce a valid module name"))?;
+    Ok(result)
+}
+
+fn render_placeholder(
+    placeholder: &str,
+    metadata: &ProblemMetadata,
+    title_slug: &str,
+) -> anyhow::Result<String> {
+    let (name, width) = match placeholder.split_once(':') {
+        Some((name, spec)) => {
+            let Some(width) = spec.strip_prefix('0').and_then(|x| x.parse::<usize>().ok()) else {
+                bail!("unsupported format {spec:?}, only zero padding (e.g. `:04`) is supported");
+            };
+            (name.trim(), Some(width))
+        }
+        None => (placeholder.trim(), None),
+    };
+    match (name, width) {
+        ("id", Some(width)) => Ok(format!("{:0width$}", metadata.id)),
+        ("id", None) => Ok(metadata.id.to_string()),
+        (_, Some(_)) => bail!("padding is only supported for `id` but found it on {name:?}"),
+        ("slug", None) => Ok(title_slug.to_case(Case::Snake)),
+        ("difficulty", None) => Ok(metadata
+            .difficulty
+            .as_deref()
+            .context("difficulty of the problem is not known")?
+            .to_lowercase()),
+        _ => bail!("unknown placeholder {name:?}. Known placeholders are: id, slug, difficulty"),
+    }
+}
+
+fn validate_identifier(name: &str) -> anyhow::Result<()> {
+    if name.is_empty() || name == "_" {
+        bail!("{name:?} is not a valid identifier");
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || *c == '_'))
+    {
+        bail!("{name:?} contains {c:?} which is not allowed in an identifier");
+    }
+    if KEYWORDS.contains(&name) {
+        bail!("{name:?} is a reserved keyword");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::literal_string_with_formatting_args)] // Patterns intentionally look like format strings
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn metadata(id: u16) -> ProblemMetadata {
+        ProblemMetadata {
+            id,
+            title: "Two Sum".into(),
+            example_test_case_list: vec![],
+            difficulty: Some("Easy".into()),
+            topic_tags: vec![],
+        }
+    }
+
+    #[rstest]
+    #[case(PATTERN_WITHOUT_NUMBER, 1, "two-sum", "two_sum")]
+    #[case(PATTERN_WITH_NUMBER, 1, "two-sum", "_1_two_sum")]
+    #[case("p{id:04}_{slug}", 1, "two-sum", "p0001_two_sum")]
+    #[case("p{id:04}_{slug}", 12345, "two-sum", "p12345_two_sum")]
+    #[case("{difficulty}_{slug}", 1, "two-sum", "easy_two_sum")]
+    #[case("{slug}", 15, "3sum", "_3_sum")]
+    #[case("{id}", 1, "two-sum", "_1")]
+    fn valid_patterns(
+        #[case] pattern: &str,
+        #[case] id: u16,
+        #[case] title_slug: &str,
+        #[case] expected: &str,
+    ) {
+        let actual = module_name(pattern, &metadata(id), title_slug).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case::unknown_placeholder("{name}")]
+    #[case::unterminated("{slug")]
+    #[case::padding_on_slug("{slug:04}")]
+    #[case::unsupported_format("{id:>4}")]
+    #[case::invalid_character("{slug}-{id}")]
+    #[case::keyword("match")]
+    #[case::empty("")]
+    fn invalid_patterns(#[case] pattern: &str) {
+        assert!(module_name(pattern, &metadata(1), "two-sum").is_err());
+    }
+}
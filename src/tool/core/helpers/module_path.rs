@@ -0,0 +1,198 @@
+//! Location of generated modules within the project
+
+use std::{
+    fmt::{self, Display},
+    path::PathBuf,
+    str::FromStr,
+};
+
+use anyhow::bail;
+use convert_case::{Case, Casing};
+
+use crate::tool::{
+    config_file::Layout, core::helpers::problem_metadata::data_structure::ProblemMetadata,
+};
+
+/// Number of problems grouped together by [`Layout::IdRange`]
+const ID_RANGE_SIZE: u16 = 100;
+
+/// Path of a module from the crate root, for example `easy::two_sum`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ModulePath {
+    /// Modules containing this one, outermost first (empty if declared in
+    /// `src/lib.rs`)
+    parents: Vec<String>,
+    name: String,
+}
+
+impl ModulePath {
+    /// Places the module named `name` according to `layout`
+    pub(crate) fn new(layout: Layout, metadata: &ProblemMetadata, name: String) -> Self {
+        let parents = match layout {
+            Layout::Flat => vec![],
+            Layout::Difficulty => vec![
+                metadata
+                    .difficulty
+                    .as_deref()
+                    .map_or_else(|| "unknown_difficulty".to_string(), str::to_lowercase),
+            ],
+            Layout::Topic => vec![
+                metadata
+                    .topic_tags
+                    .first()
+                    .map_or_else(|| "untagged".to_string(), |tag| tag.to_case(Case::Snake)),
+            ],
+            Layout::IdRange => {
+                let start = (metadata.id.saturating_sub(1) / ID_RANGE_SIZE) * ID_RANGE_SIZE + 1;
+                let end = start + ID_RANGE_SIZE - 1;
+                vec![format!("p{start:04}_{end:04}")]
+            }
+        };
+        Self { parents, name }
+    }
+
+    /// Module declared directly in `src/lib.rs`
+    pub(crate) const fn top_level(name: String) -> Self {
+        Self {
+            parents: vec![],
+            name,
+        }
+    }
+
+    /// Module declared inside of this one
+    pub(crate) fn child(&self, name: String) -> Self {
+        let mut parents = self.parents.clone();
+        parents.push(self.name.clone());
+        Self { parents, name }
+    }
+
+    /// Module in the same location but with a different name
+    pub(crate) fn with_name(&self, name: String) -> Self {
+        Self {
+            parents: self.parents.clone(),
+            name,
+        }
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// File containing the code of the module
+    ///
+    /// Modules with children are expected to use `mod.rs`
+    pub(crate) fn file_path(&self) -> PathBuf {
+        self.dir_path().join(format!("{}.rs", self.name))
+    }
+
+    /// File used by a module that contains other modules
+    pub(crate) fn mod_rs_path(&self) -> PathBuf {
+        self.dir_path().join(&self.name).join("mod.rs")
+    }
+
+    /// Pairs of the file declaring each module along the path and the name of
+    /// the module declared, starting with the declaration in `src/lib.rs`
+    pub(crate) fn declarations(&self) -> Vec<(PathBuf, &str)> {
+        let mut result = Vec::with_capacity(self.parents.len() + 1);
+        let mut dir = PathBuf::from("src");
+        let mut declaring_file = dir.join("lib.rs");
+        for parent in &self.parents {
+            result.push((declaring_file, parent.as_str()));
+            dir = dir.join(parent);
+            declaring_file = dir.join("mod.rs");
+        }
+        result.push((declaring_file, self.name.as_str()));
+        result
+    }
+
+    fn dir_path(&self) -> PathBuf {
+        let mut result = PathBuf::from("src");
+        result.extend(&self.parents);
+        result
+    }
+}
+
+impl Display for ModulePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for parent in &self.parents {
+            write!(f, "{parent}::")?;
+        }
+        write!(f, "{}", self.name)
+    }
+}
+
+impl FromStr for ModulePath {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts: Vec<String> = s.split("::").map(|part| part.trim().to_string()).collect();
+        if parts.iter().any(String::is_empty) {
+            bail!("invalid module path {s:?}");
+        }
+        let name = parts.pop().expect("split always returns at least one part");
+        Ok(Self {
+            parents: parts,
+            name,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn metadata(id: u16) -> ProblemMetadata {
+        ProblemMetadata {
+            id,
+            title: "Two Sum".into(),
+            example_test_case_list: vec![],
+            difficulty: Some("Easy".into()),
+            topic_tags: vec!["Hash Table".into(), "Array".into()],
+        }
+    }
+
+    #[rstest]
+    #[case(Layout::Flat, 1, "two_sum")]
+    #[case(Layout::Difficulty, 1, "easy::two_sum")]
+    #[case(Layout::Topic, 1, "hash_table::two_sum")]
+    #[case(Layout::IdRange, 1, "p0001_0100::two_sum")]
+    #[case(Layout::IdRange, 100, "p0001_0100::two_sum")]
+    #[case(Layout::IdRange, 101, "p0101_0200::two_sum")]
+    fn module_path_from_layout(#[case] layout: Layout, #[case] id: u16, #[case] expected: &str) {
+        let actual = ModulePath::new(layout, &metadata(id), "two_sum".into());
+        assert_eq!(actual.to_string(), expected);
+    }
+
+    #[test]
+    fn paths_of_nested_module() {
+        let module_path: ModulePath = "easy::two_sum".parse().unwrap();
+        assert_eq!(
+            module_path.file_path(),
+            PathBuf::from("src/easy/two_sum.rs")
+        );
+        assert_eq!(
+            module_path.declarations(),
+            vec![
+                (PathBuf::from("src/lib.rs"), "easy"),
+                (PathBuf::from("src/easy/mod.rs"), "two_sum")
+            ]
+        );
+    }
+
+    #[test]
+    fn paths_of_top_level_module() {
+        let module_path: ModulePath = "two_sum".parse().unwrap();
+        assert_eq!(module_path.file_path(), PathBuf::from("src/two_sum.rs"));
+        assert_eq!(
+            module_path.declarations(),
+            vec![(PathBuf::from("src/lib.rs"), "two_sum")]
+        );
+    }
+
+    #[test]
+    fn invalid_module_path() {
+        assert!("easy::".parse::<ModulePath>().is_err());
+    }
+}
@@ -0,0 +1,79 @@
+//! Category of a problem as given by leetcode in `categoryTitle`
+//!
+//! Used to explain why there is no rust code for a problem and to generate
+//! tests that fit the category (e.g. threads for concurrency problems)
+
+use log::warn;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(from = "String")]
+pub(crate) enum ProblemCategory {
+    /// Also used if the category is not known (e.g. submitted code)
+    #[default]
+    Algorithms,
+    Database,
+    Shell,
+    Concurrency,
+    JavaScript,
+    Pandas,
+    /// A category not known to this version of the tool
+    Other,
+}
+
+impl From<String> for ProblemCategory {
+    fn from(value: String) -> Self {
+        match value.to_lowercase().as_str() {
+            "algorithms" => Self::Algorithms,
+            "database" => Self::Database,
+            "shell" => Self::Shell,
+            "concurrency" => Self::Concurrency,
+            "javascript" => Self::JavaScript,
+            "pandas" => Self::Pandas,
+            _ => {
+                warn!("Unknown problem category {value:?}");
+                Self::Other
+            }
+        }
+    }
+}
+
+impl ProblemCategory {
+    /// Explains why there is no rust snippet for a problem of this category
+    pub(crate) const fn no_rust_message(self) -> &'static str {
+        match self {
+            Self::Database => {
+                "This is a database problem which is solved in SQL, Rust is not supported for it"
+            }
+            Self::Shell => {
+                "This is a shell problem which is solved with a bash script, Rust is not supported for it"
+            }
+            Self::Pandas => {
+                "This is a pandas problem which is solved in Python, Rust is not supported for it"
+            }
+            Self::JavaScript => {
+                "This is a JavaScript problem which is solved in JavaScript or TypeScript, Rust is not supported for it"
+            }
+            Self::Concurrency => "Rust is not supported for this concurrency problem",
+            Self::Algorithms | Self::Other => "Rust not supported for this problem",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("Algorithms", ProblemCategory::Algorithms)]
+    #[case("Database", ProblemCategory::Database)]
+    #[case("Shell", ProblemCategory::Shell)]
+    #[case("Concurrency", ProblemCategory::Concurrency)]
+    #[case("JavaScript", ProblemCategory::JavaScript)]
+    #[case("pandas", ProblemCategory::Pandas)]
+    #[case("Brainteasers", ProblemCategory::Other)]
+    fn from_category_title(#[case] title: &str, #[case] expected: ProblemCategory) {
+        assert_eq!(ProblemCategory::from(title.to_string()), expected);
+    }
+}
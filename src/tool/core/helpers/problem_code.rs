@@ -1,8 +1,23 @@
-use anyhow::{Context, bail};
+use anyhow::Context;
 use log::{debug, error, info, warn};
-use regex::Regex;
 use std::fmt::Write;
-use strum::IntoEnumIterator as _;
+
+use super::{
+    checker::{CHECK_FN_NAME, Checker, CheckerConfig},
+    problem_category::ProblemCategory,
+    problem_description::constraints::Constraint,
+};
+use crate::tool::config_file::OutputOrder;
+use arg_type::FunctionArgType;
+pub(crate) use concurrency::ConcurrencyInfo;
+use helper_type::HelperType;
+use judge_api::JudgeApi;
+
+mod arg_type;
+mod concurrency;
+mod helper_type;
+mod judge_api;
+mod signature;
 
 #[derive(Debug)]
 pub(crate) struct ProblemCode {
@@ -14,6 +29,8 @@ pub(crate) struct ProblemCode {
 pub(crate) enum ProblemType {
     NonDesign(FunctionInfo),
     Design,
+    /// Methods called from multiple threads
+    Concurrency(ConcurrencyInfo),
 }
 
 impl ProblemType {
@@ -29,8 +46,27 @@ impl ProblemType {
 impl TryFrom<String> for ProblemCode {
     type Error = anyhow::Error;
 
+    /// Used when the category is not known (e.g. submitted code), see
+    /// [`ProblemCode::new`]
     fn try_from(code: String) -> Result<Self, Self::Error> {
-        let type_ = if Self::is_design(&code) {
+        Self::new(code, ProblemCategory::default())
+    }
+}
+
+impl AsRef<str> for ProblemCode {
+    fn as_ref(&self) -> &str {
+        &self.code
+    }
+}
+
+impl ProblemCode {
+    pub(crate) fn new(code: String, category: ProblemCategory) -> anyhow::Result<Self> {
+        let type_ = if category == ProblemCategory::Concurrency {
+            info!("Problem Type is Concurrency");
+            ProblemType::Concurrency(
+                ConcurrencyInfo::new(&code).context("Failed to get concurrency info")?,
+            )
+        } else if Self::is_design(&code) {
             info!("Problem Type is Design");
             ProblemType::Design
         } else {
@@ -41,44 +77,34 @@ impl TryFrom<String> for ProblemCode {
         debug!("ProblemCode built: {result:#?}");
         Ok(result)
     }
-}
 
-impl AsRef<str> for ProblemCode {
-    fn as_ref(&self) -> &str {
-        &self.code
-    }
-}
-
-impl ProblemCode {
     fn is_design(code: &str) -> bool {
         !code.contains("impl Solution {")
     }
 
     fn get_fn_info(code: &str) -> anyhow::Result<FunctionInfo> {
-        let re = Regex::new(r"(?s)\n\s*pub fn ([a-z_0-9]*)\s*\((.*)\)(?: ?-> ?(.*))? \{")?;
-        let Some(caps) = re.captures(code) else {
-            bail!("Regex failed to match");
-        };
-
-        let name = if let Some(name) = caps.get(1) {
-            name.as_str().to_string()
-        } else {
-            bail!("Function name not found in code")
-        };
-
-        let args = FunctionArgs::new(if let Some(args) = caps.get(2) {
-            args.as_str().to_string()
-        } else {
-            bail!("Function arguments not matched")
-        })
-        .context("Failed to parse function arguments")?;
-
-        let return_type: Option<FunctionArgType> = caps.get(3).map(|s| s.as_str().into());
-
+        let signature = signature::parse(code).context("Failed to parse function signature")?;
+        let fn_args = FunctionArgs::new(signature.params);
+        let return_type: Option<FunctionArgType> = signature.return_type.as_deref().map(Into::into);
+        let printed_output =
+            if return_type.is_none() && !fn_args.args.iter().any(|arg| arg.is_mut_ref) {
+                fn_args
+                    .args
+                    .iter()
+                    .find_map(|arg| arg.arg_type.printed_type())
+            } else {
+                None
+            };
         Ok(FunctionInfo {
-            name,
-            fn_args: args,
+            name: signature.name,
+            fn_args,
             return_type,
+            printed_output,
+            output_order: OutputOrder::Exact,
+            judge_api: JudgeApi::detect(code),
+            is_unsafe: signature.is_unsafe,
+            is_method: signature.has_receiver,
+            checker: None,
         })
     }
 
@@ -97,6 +123,64 @@ impl ProblemCode {
             false
         }
     }
+
+    /// Names of the types and judge APIs leetcode defines for the problem that
+    /// are imported from `cargo_leet` (e.g. `NestedInteger`, `guess`)
+    pub(crate) fn get_cargo_leet_imports(&self) -> Vec<&'static str> {
+        let identifiers = signature::identifiers(&self.code).unwrap_or_else(|e| {
+            warn!("Unable to look for helper types in the code: {e:?}");
+            vec![]
+        });
+        // Types the code defines itself are not imported (e.g. `Node` of a
+        // linked list with a random pointer)
+        let defined = signature::defined_types(&self.code).unwrap_or_default();
+        let mut result: Vec<_> = identifiers
+            .into_iter()
+            .filter(|identifier| !defined.contains(identifier))
+            .filter_map(HelperType::from_name)
+            .map(HelperType::name)
+            .chain(JudgeApi::detect(&self.code).map(JudgeApi::import_name))
+            .collect();
+        result.sort_unstable();
+        result.dedup();
+        result
+    }
+
+    /// Attaches each constraint to the arguments it applies to
+    pub(crate) fn add_constraints(&mut self, constraints: &[Constraint]) {
+        if let ProblemType::NonDesign(fn_info) = &mut self.type_ {
+            for arg in &mut fn_info.fn_args.args {
+                let is_list_or_tree = arg.arg_type.is_list() || arg.arg_type.is_tree();
+                arg.constraints.extend(
+                    constraints
+                        .iter()
+                        .filter(|constraint| constraint.is_for(&arg.identifier, is_list_or_tree))
+                        .cloned(),
+                );
+            }
+        }
+    }
+
+    /// Routes the checks of the answers through `check_answer` (see
+    /// [`CheckerConfig`])
+    pub(crate) fn set_checker(&mut self, checker: CheckerConfig) {
+        if let ProblemType::NonDesign(fn_info) = &mut self.type_ {
+            if fn_info.get_expected_type().is_some() {
+                fn_info.checker = Some(checker);
+            } else {
+                warn!("Checker ignored as the function does not return a value");
+            }
+        } else {
+            warn!("Checker ignored as it is only supported for non design problems");
+        }
+    }
+
+    /// Sets how the output of the function is compared in the tests
+    pub(crate) const fn set_output_order(&mut self, output_order: OutputOrder) {
+        if let ProblemType::NonDesign(fn_info) = &mut self.type_ {
+            fn_info.output_order = output_order;
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -104,20 +188,144 @@ pub(crate) struct FunctionInfo {
     pub(crate) name: String,
     fn_args: FunctionArgs,
     return_type: Option<FunctionArgType>,
+    /// Type of the values printed by the solution (e.g. with
+    /// `ImmutableListNode::printValue`) which are checked if it does not return
+    /// a value
+    printed_output: Option<FunctionArgType>,
+    output_order: OutputOrder,
+    /// Judge API whose secret is given after the arguments in the examples
+    judge_api: Option<JudgeApi>,
+    is_unsafe: bool,
+    /// Takes `self` so it is called on `Solution` (a unit struct)
+    is_method: bool,
+    /// Answers are checked by `check_answer` instead of comparing them with
+    /// the expected answer
+    checker: Option<CheckerConfig>,
 }
 
 impl FunctionInfo {
     pub(crate) fn get_args_with_case(&self) -> String {
-        let mut result = String::from("#[case] ");
-        result.push_str(&self.fn_args.raw_str.replace(',', ", #[case] "));
+        let params: Vec<_> = self
+            .get_owned_params()
+            .iter()
+            .map(|param| format!("#[case] {param}"))
+            .collect();
+        let mut result = params.join(", ");
+        if let Some(expected_type) = self.get_expected_type() {
+            write!(result, ", #[case] expected: {expected_type}").expect("write! macro failed");
+        }
+        result
+    }
 
-        if let Some(return_type) = self.return_type.as_ref() {
-            write!(result, ", #[case] expected: {}", return_type.as_str())
-                .expect("write! macro failed");
+    /// Arguments as written in the function signature followed by the expected
+    /// result if the function returns a value or mutates an argument
+    pub(crate) fn get_args_with_types(&self) -> String {
+        let mut result = self.get_owned_params().join(", ");
+        if let Some(expected_type) = self.get_expected_type() {
+            write!(result, ", expected: {expected_type}").expect("write! macro failed");
         }
         result
     }
 
+    /// Parameters as in the function signature except that arguments taken by
+    /// reference are taken by value (as `mut` for `&mut`) so tests can own
+    /// them, followed by the secret of the judge API if there is one
+    fn get_owned_params(&self) -> Vec<String> {
+        self.fn_args
+            .args
+            .iter()
+            .map(|arg| {
+                format!(
+                    "{}{}: {}",
+                    if arg.is_mut_ref { "mut " } else { "" },
+                    arg.identifier,
+                    arg.arg_type
+                )
+            })
+            .chain(
+                self.judge_api
+                    .map(|api| format!("{}: i32", api.secret_name())),
+            )
+            .collect()
+    }
+
+    /// Parameters as in the function signature
+    fn get_params(&self) -> String {
+        let params: Vec<_> = self
+            .fn_args
+            .args
+            .iter()
+            .map(|arg| format!("{}: {}{}", arg.identifier, arg.reference(), arg.arg_type))
+            .collect();
+        params.join(", ")
+    }
+
+    /// Type of the value checked after calling the function, the return type
+    /// or if there is none the type of the argument mutated in place or of the
+    /// values printed
+    fn get_expected_type(&self) -> Option<&FunctionArgType> {
+        self.return_type
+            .as_ref()
+            .or_else(|| self.get_mutated_arg().map(|arg| &arg.arg_type))
+            .or(self.printed_output.as_ref())
+    }
+
+    /// Argument taken by `&mut` whose final state is checked for functions
+    /// that do not return a value (e.g. `rotate(nums: &mut Vec<i32>, k: i32)`)
+    fn get_mutated_arg(&self) -> Option<&FunctionArg> {
+        if self.return_type.is_some() {
+            return None;
+        }
+        self.fn_args.args.iter().find(|arg| arg.is_mut_ref)
+    }
+
+    /// Number of values in each test case (arguments, secret of the judge API
+    /// and expected result)
+    pub(crate) fn get_case_len(&self) -> usize {
+        self.fn_args.len()
+            + usize::from(self.judge_api.is_some())
+            + usize::from(self.get_expected_type().is_some())
+    }
+
+    /// Names of the arguments followed by the secret of the judge API and the
+    /// expected result if there are
+    pub(crate) fn get_case_names(&self) -> String {
+        let mut result = self.get_args_names();
+        if let Some(api) = self.judge_api {
+            write!(result, ", {}", api.secret_name()).expect("write! macro failed");
+        }
+        if self.get_expected_type().is_some() {
+            result.push_str(", expected");
+        }
+        result
+    }
+
+    /// Tuple type able to hold all the values of a test case
+    pub(crate) fn get_case_tuple_type(&self) -> String {
+        let types: Vec<_> = self
+            .fn_args
+            .args
+            .iter()
+            .map(|arg| arg.arg_type.to_string())
+            .chain(self.judge_api.map(|_| "i32".to_string()))
+            .chain(self.get_expected_type().map(ToString::to_string))
+            .collect();
+        Self::as_tuple(&types.join(", "), types.len())
+    }
+
+    /// Pattern to destructure a tuple from [`Self::get_case_tuple_type`]
+    pub(crate) fn get_case_tuple_pattern(&self) -> String {
+        Self::as_tuple(&self.get_case_names(), self.get_case_len())
+    }
+
+    fn as_tuple(inner: &str, len: usize) -> String {
+        if len == 1 {
+            format!("({inner},)")
+        } else {
+            format!("({inner})")
+        }
+    }
+
     pub(crate) fn get_args_names(&self) -> String {
         let names: Vec<_> = self
             .fn_args
@@ -128,26 +336,187 @@ impl FunctionInfo {
         names.join(", ")
     }
 
+    /// Arguments to call the function with (`&mut` or `&` added where needed)
+    ///
+    /// With a checker the arguments are cloned as they are passed to it after
+    /// the call
+    fn get_call_args(&self) -> String {
+        let args: Vec<_> = self
+            .fn_args
+            .args
+            .iter()
+            .map(|arg| {
+                let should_clone = self.checker.is_some()
+                    && !arg.is_mut_ref
+                    && !arg.is_ref
+                    && !arg.arg_type.is_copy();
+                format!(
+                    "{}{}{}",
+                    arg.reference(),
+                    arg.identifier,
+                    if should_clone { ".clone()" } else { "" }
+                )
+            })
+            .collect();
+        args.join(", ")
+    }
+
+    /// Expression that calls the solution with the arguments
+    fn get_solution_call(&self) -> String {
+        let call = format!(
+            "Solution{}{}({})",
+            if self.is_method { "." } else { "::" },
+            self.name,
+            self.get_call_args()
+        );
+        if self.is_unsafe {
+            format!("unsafe {{ {call} }}")
+        } else {
+            call
+        }
+    }
+
+    /// Code that calls the solution with the arguments and checks the result
+    /// against `expected`
+    ///
+    /// `example` is the variable holding the number of the example, it is
+    /// included in the message of the assertion if given
+    pub(crate) fn get_solution_check_code(&self, example: Option<&str>) -> String {
+        let call = self.get_solution_call();
+        let check = if self.return_type.is_some() {
+            format!(
+                "let actual = {call};\n        {}",
+                self.get_solution_comparison_code(example)
+            )
+        } else if let Some(arg) = self.get_mutated_arg() {
+            // The checker is given the argument as it was before the call
+            let original = if self.checker.is_some() {
+                format!("let original_{0} = {0}.clone();\n        ", arg.identifier)
+            } else {
+                String::new()
+            };
+            format!(
+                "{original}{call};\n        let actual = {};\n        {}",
+                arg.identifier,
+                self.get_solution_comparison_code(example)
+            )
+        } else if self.printed_output.is_some() {
+            format!(
+                "{call};\n        let actual = cargo_leet::take_printed_values();\n        {}",
+                self.get_solution_comparison_code(example)
+            )
+        } else {
+            format!("{call};")
+        };
+        match self.judge_api {
+            Some(api) => format!(
+                "cargo_leet::set_secret({});\n        {check}",
+                api.secret_name()
+            ),
+            None => check,
+        }
+    }
+
     // Allow warning because this is actually code to be inserted into the generated code
     #[allow(clippy::literal_string_with_formatting_args)]
-    pub(crate) fn get_solution_comparison_code(&self) -> String {
-        if matches!(&self.return_type, Some(FunctionArgType::F64)) {
-            "assert!((actual - expected).abs() < 1e-5, \"Assertion failed: actual {actual:.5} but expected {expected:.5}. Diff is more than 1e-5.\");"
-        } else {
-            "assert_eq!(actual, expected);"
+    pub(crate) fn get_solution_comparison_code(&self, example: Option<&str>) -> String {
+        let message = example.map_or_else(String::new, |example| {
+            format!(", \"example {{{example}}}\"")
+        });
+        if self.checker.is_some() {
+            let mutated = self.get_mutated_arg().map(|arg| &arg.identifier);
+            let args: Vec<_> = self
+                .fn_args
+                .args
+                .iter()
+                .map(|arg| {
+                    if Some(&arg.identifier) == mutated {
+                        format!("&original_{}, ", arg.identifier)
+                    } else {
+                        format!("&{}, ", arg.identifier)
+                    }
+                })
+                .collect();
+            let for_example =
+                example.map_or_else(String::new, |example| format!(" for example {{{example}}}"));
+            return format!(
+                "assert!({CHECK_FN_NAME}({}&actual, &expected), \"{{actual:?}} is not a valid answer{for_example}\");",
+                args.concat()
+            );
+        }
+        if self
+            .get_expected_type()
+            .is_some_and(FunctionArgType::has_f64)
+        {
+            return if example.is_some() {
+                format!(
+                    "cargo_leet::assert_approx_eq!(actual, expected, cargo_leet::DEFAULT_APPROX_TOLERANCE{message});"
+                )
+            } else {
+                "cargo_leet::assert_approx_eq!(actual, expected);".to_string()
+            };
+        }
+        let helper = self.get_output_order_helper();
+        helper.map_or_else(
+            || format!("assert_eq!(actual, expected{message});"),
+            |helper| {
+                format!(
+                    "assert_eq!(cargo_leet::{helper}(actual), cargo_leet::{helper}(expected){message});"
+                )
+            },
+        )
+    }
+
+    /// Name of the `cargo_leet` function that removes the order of the output
+    /// before comparing, `None` if it is compared as is
+    fn get_output_order_helper(&self) -> Option<&'static str> {
+        match (self.output_order, self.get_expected_type()) {
+            (OutputOrder::Exact, _) => None,
+            (OutputOrder::Any, Some(return_type)) if return_type.is_nested_vec() => {
+                Some("any_order_nested")
+            }
+            (OutputOrder::Any | OutputOrder::AnyOuter, Some(return_type))
+                if return_type.is_sortable_vec() =>
+            {
+                Some("any_order")
+            }
+            (OutputOrder::Any | OutputOrder::AnyOuter, _) => {
+                info!(
+                    "Comparing exactly as the type {:?} cannot be sorted",
+                    self.get_expected_type()
+                );
+                None
+            }
         }
-            .to_string()
     }
 
-    pub(crate) fn get_test_case(&self, example_test_case_raw: &str, solution: &str) -> String {
-        let mut result = String::new();
-        let n = self.fn_args.len();
+    /// Converts the raw example into the values of a test case separated by
+    /// commas.
+    ///
+    /// `is_rstest` should be set if the values are used in a `#[case]` attribute
+    /// as rstest converts string literals into [`String`]
+    pub(crate) fn get_test_case(
+        &self,
+        example_test_case_raw: &str,
+        solution: &str,
+        is_rstest: bool,
+    ) -> String {
+        let secret_type = FunctionArgType::from("i32");
+        let mut values: Vec<_> = self
+            .fn_args
+            .args
+            .iter()
+            .map(|arg| (format!("argument `{}`", arg.identifier), &arg.arg_type))
+            .collect();
+        if let Some(api) = self.judge_api {
+            values.push((format!("secret `{}`", api.secret_name()), &secret_type));
+        }
         let lines: Vec<_> = example_test_case_raw.lines().collect();
 
-        if lines.len() != self.fn_args.len() {
+        if lines.len() != values.len() {
             let err_msg = format!(
                 "expected number of augments ({}) to match the number of lines downloaded ({})",
-                self.fn_args.len(),
+                values.len(),
                 lines.len()
             );
             debug!("FunctionInfo:\n{self:#?}");
@@ -159,28 +528,251 @@ impl FunctionInfo {
             );
         }
 
-        for (i, (&line, arg_type)) in lines
-            .iter()
-            .zip(self.fn_args.args.iter().map(|arg| &arg.arg_type))
-            .enumerate()
-        {
-            result.push_str(&arg_type.apply(line));
+        let types: Vec<_> = values.iter().map(|(_, arg_type)| *arg_type).collect();
+        let mut result: Vec<_> = Self::match_lines(&types, &lines)
+            .into_iter()
+            .zip(&values)
+            .map(|(line, (name, arg_type))| arg_type.apply(name, line, is_rstest))
+            .collect();
 
-            if i < n - 1 {
-                result.push_str(", ");
-            }
+        // Include return type (or the final value of the mutated argument)
+        if let Some(ret_type) = self.get_expected_type() {
+            result.push(ret_type.apply("expected result", solution, is_rstest));
         }
 
-        // Include return type
-        if let Some(ret_type) = &self.return_type {
-            let sol = ret_type.apply(solution);
-            result.push_str(", ");
-            result.push_str(&sol);
-        }
+        result.join(", ")
+    }
 
+    /// Picks the line of the example for each of `types`
+    ///
+    /// The lines are usually in the order of the arguments but some examples
+    /// list them in a different order (e.g. "Find in Mountain Array" gives the
+    /// array before the target), so if the types do not match in order the
+    /// first unused line that matches is taken for each type. Falls back to
+    /// the original order if that does not match all the types either
+    fn match_lines<'a>(types: &[&FunctionArgType], lines: &[&'a str]) -> Vec<&'a str> {
+        if types.iter().zip(lines).all(|(t, line)| t.accepts(line)) {
+            return lines.to_vec();
+        }
+        let mut unused = lines.to_vec();
+        let mut result = Vec::with_capacity(types.len());
+        for arg_type in types {
+            let Some(i) = unused.iter().position(|line| arg_type.accepts(line)) else {
+                info!("Example lines do not match the types in any order, keeping their order");
+                return lines.to_vec();
+            };
+            result.push(unused.remove(i));
+        }
+        info!("Example lines reordered to match the types of the arguments");
         result
     }
 
+    /// Ignored test that calls the function with the largest inputs allowed by
+    /// the constraints to find solutions that are too slow or overflow
+    ///
+    /// Returns `None` if an argument has a type that cannot be built
+    pub(crate) fn get_stress_test(&self) -> Option<String> {
+        if self.judge_api.is_some() {
+            info!("Skipping stress test as the secret of the judge API is not known");
+            return None;
+        }
+        let mut result = "#[test]
+    #[ignore = \"stress test, run with `cargo test --release -- --ignored`\"]
+    fn stress_maximal_input() {
+"
+        .to_string();
+        for arg in &self.fn_args.args {
+            let Some(value) = arg.arg_type.get_maximal_value_code(&arg.constraints) else {
+                info!(
+                    "Skipping stress test as unable to build a value of type {:?}",
+                    arg.arg_type
+                );
+                return None;
+            };
+            writeln!(
+                result,
+                "        let {}{}: {} = {value};",
+                if arg.is_mut_ref { "mut " } else { "" },
+                arg.identifier,
+                arg.arg_type
+            )
+            .expect("write! macro failed");
+        }
+        let call = self.get_solution_call();
+        write!(
+            result,
+            "        let start = std::time::Instant::now();
+        {};
+        let elapsed = start.elapsed();
+        assert!(
+            elapsed < std::time::Duration::from_secs(1),
+            \"took {{elapsed:?}} with maximal input\"
+        );
+    }}",
+            if self.return_type.is_some() {
+                format!("let _ = {call}")
+            } else {
+                call
+            }
+        )
+        .expect("write! macro failed");
+        Some(result)
+    }
+
+    /// A `brute_force` stub for the user to fill in and an ignored test that
+    /// compares it with the solution on small random inputs (see
+    /// `cargo_leet::Fuzz`)
+    ///
+    /// Returns `None` if the function does not return a value or an argument
+    /// has a type that cannot be generated
+    pub(crate) fn get_fuzz_test(&self) -> Option<String> {
+        if self.get_expected_type().is_none() {
+            info!("Skipping fuzz test as there is no value to compare");
+            return None;
+        }
+        if self.judge_api.is_some() {
+            info!("Skipping fuzz test as the secret of the judge API is not generated");
+            return None;
+        }
+        if self.checker.is_some() {
+            info!("Skipping fuzz test as answers other than the brute force one may be valid");
+            return None;
+        }
+        let mut generators = vec![];
+        for arg in &self.fn_args.args {
+            let Some(generator) = arg.arg_type.get_random_value_code(&arg.constraints) else {
+                info!(
+                    "Skipping fuzz test as unable to generate a value of type {:?}",
+                    arg.arg_type
+                );
+                return None;
+            };
+            generators.push(generator);
+        }
+        let pattern_names: Vec<_> = self
+            .fn_args
+            .args
+            .iter()
+            .map(|arg| {
+                if arg.is_mut_ref {
+                    format!("mut {}", arg.identifier)
+                } else {
+                    arg.identifier.clone()
+                }
+            })
+            .collect();
+        let pattern = Self::as_tuple(&pattern_names.join(", "), pattern_names.len());
+        let call = |function: Option<&str>| {
+            let call = function.map_or_else(
+                || self.get_solution_call(),
+                |function| format!("{function}({})", self.get_call_args()),
+            );
+            let call = match self.get_mutated_arg() {
+                Some(arg) => format!("{{ {call}; {} }}", arg.identifier),
+                None if self.printed_output.is_some() => {
+                    format!("{{ {call}; cargo_leet::take_printed_values() }}")
+                }
+                None => call,
+            };
+            self.get_output_order_helper().map_or_else(
+                || call.clone(),
+                |helper| format!("cargo_leet::{helper}({call})"),
+            )
+        };
+        Some(format!(
+            "/// Straightforward (but slow) solution used to check [`Solution::{name}`]
+    fn brute_force({args}){return_type} {{
+        todo!(\"Write a brute force solution\")
+    }}
+
+    #[test]
+    #[ignore = \"differential test, write `brute_force` then run with `cargo test -- --ignored`\"]
+    fn fuzz_against_brute_force() {{
+        cargo_leet::Fuzz::new().differential(
+            |g| {generators},
+            |{pattern}| {solution_call},
+            |{pattern}| {brute_force_call},
+        );
+    }}",
+            name = self.name,
+            args = self.get_params(),
+            return_type = self
+                .return_type
+                .as_ref()
+                .map_or_else(String::new, |return_type| format!(" -> {return_type}")),
+            generators = Self::as_tuple(&generators.join(", "), generators.len()),
+            solution_call = call(None),
+            brute_force_call = call(Some("brute_force")),
+        ))
+    }
+
+    /// The `check_answer` function used by the tests if a checker is selected
+    /// and it is not written by the user
+    pub(crate) fn get_check_answer_fn(&self) -> Option<String> {
+        let checker = self.checker.filter(|checker| !checker.is_user_defined)?;
+        let expected_type = self.get_expected_type()?;
+        let find_arg = |is_match: &dyn Fn(&FunctionArgType) -> bool| {
+            self.fn_args
+                .args
+                .iter()
+                .find(|arg| is_match(&arg.arg_type))
+                .map(|arg| arg.identifier.as_str())
+        };
+        let todo = "todo!(\"Return true if `actual` is a valid answer\")".to_string();
+        let body = match checker.checker {
+            Checker::Custom => todo,
+            Checker::IsPermutationOf => {
+                format!("cargo_leet::{}(actual, expected)", checker.checker.name())
+            }
+            Checker::IsSortedSubsequenceOf => {
+                find_arg(&|t| t == expected_type).map_or(todo, |sequence| {
+                    format!(
+                        "actual.len() == expected.len() && cargo_leet::{}(actual, {sequence})",
+                        checker.checker.name()
+                    )
+                })
+            }
+            Checker::IsValidPathInGrid => {
+                find_arg(&FunctionArgType::is_grid).map_or(todo, |grid| {
+                    format!(
+                        "actual.len() == expected.len() && cargo_leet::{}({grid}, actual)",
+                        checker.checker.name()
+                    )
+                })
+            }
+        };
+        // Inputs not used by a validator are prefixed with `_` to avoid warnings
+        let used = signature::identifiers(&body).unwrap_or_default();
+        let params: Vec<_> = self
+            .fn_args
+            .args
+            .iter()
+            .map(|arg| {
+                let is_unused =
+                    checker.checker != Checker::Custom && !used.contains(&arg.identifier.as_str());
+                format!(
+                    "{}{}: &{}, ",
+                    if is_unused { "_" } else { "" },
+                    arg.identifier,
+                    arg.arg_type
+                )
+            })
+            .collect();
+        Some(format!(
+            "/// Returns `true` if `actual` is a valid answer for the input, `expected`
+    /// is the answer given in the example{}
+    fn {CHECK_FN_NAME}({}actual: &{expected_type}, expected: &{expected_type}) -> bool {{
+        {body}
+    }}",
+            if checker.checker == Checker::Custom {
+                "\n    ///\n    /// Move it above the separator so it is kept when the tests are regenerated"
+            } else {
+                ""
+            },
+            params.concat(),
+        ))
+    }
+
     fn has_tree(&self) -> bool {
         self.fn_args.args.iter().any(|arg| arg.arg_type.is_tree())
     }
@@ -194,30 +786,55 @@ impl FunctionInfo {
 pub(crate) struct FunctionArg {
     identifier: String,
     arg_type: FunctionArgType,
+    /// Taken as `&mut` (e.g. to be modified in place)
+    is_mut_ref: bool,
+    /// Taken as `&` (e.g. `&MountainArray`)
+    is_ref: bool,
+    /// Limits on the argument found in the description
+    constraints: Vec<Constraint>,
+}
+
+impl FunctionArg {
+    /// Reference taken by the function to add before the owned value
+    const fn reference(&self) -> &'static str {
+        if self.is_mut_ref {
+            "&mut "
+        } else if self.is_ref {
+            "&"
+        } else {
+            ""
+        }
+    }
 }
 
 #[derive(Debug)]
 struct FunctionArgs {
-    raw_str: String,
     args: Vec<FunctionArg>,
 }
 
 impl FunctionArgs {
-    fn new(raw_str: String) -> anyhow::Result<Self> {
-        let re = Regex::new(r"([A-Za-z_0-9]+?)\s*:\s*([A-Za-z0-9<>]*)")?;
-        let caps: Vec<_> = re.captures_iter(&raw_str).collect();
-        let mut args: Vec<FunctionArg> = vec![];
-        for cap in caps {
-            let identifier = cap.get(1).expect("Required to match").as_str().to_string();
-            let arg_type = cap.get(2).expect("Required to match").as_str().into();
-
-            args.push(FunctionArg {
-                identifier,
-                arg_type,
-            });
-        }
-
-        Ok(Self { raw_str, args })
+    fn new(params: Vec<signature::Param>) -> Self {
+        let args = params
+            .into_iter()
+            .map(|param| {
+                let arg_type: FunctionArgType = param.type_.as_str().into();
+                // References to types that cannot be owned (e.g. `&str`) are
+                // kept as they are
+                let is_ref = param.is_ref && !matches!(arg_type, FunctionArgType::Other { .. });
+                FunctionArg {
+                    identifier: param.identifier,
+                    arg_type: if param.is_ref && !is_ref {
+                        format!("&{}", param.type_).as_str().into()
+                    } else {
+                        arg_type
+                    },
+                    is_mut_ref: param.is_mut_ref,
+                    is_ref,
+                    constraints: vec![],
+                }
+            })
+            .collect();
+        Self { args }
     }
 
     const fn len(&self) -> usize {
@@ -225,162 +842,12 @@ impl FunctionArgs {
     }
 }
 
-/// Function Arg Type (FAT)
-#[derive(Debug, Eq, Hash, PartialEq, strum::EnumIter)]
-enum FunctionArgType {
-    // Search Key: SK_ADD_TYPE
-    // Add type name to enum
-    I32,
-    I64,
-    F64,
-    Bool,
-    String_,
-    VecI32,
-    VecF64,
-    VecBool,
-    VecString,
-    VecVecI32,
-    VecVecString,
-    VecVecChar,
-    List,
-    Tree,
-    Other { raw: String },
-}
-
-impl FunctionArgType {
-    /// Applies any special changes needed to the value based on the type
-    fn apply(&self, line: &str) -> String {
-        debug!("Going to apply changes to argument input for {self:#?} to {line:?}");
-        let result = match self {
-            // Search Key: SK_ADD_TYPE
-            // Add how string of type should be modified for code saved for user
-            Self::String_ | Self::Bool => Ok(line.to_string()),
-            Self::I32 => match line.parse::<i32>() {
-                Ok(_) => Ok(line.to_string()),
-                Err(e) => Err(format!(
-                    "In testing the test input {line:?} the parsing to i32 failed with error: {e}"
-                )),
-            },
-            Self::I64 => match line.parse::<i64>() {
-                Ok(_) => Ok(line.to_string()),
-                Err(e) => Err(format!(
-                    "In testing the test input {line:?} the parsing to i64 failed with error: {e}"
-                )),
-            },
-            Self::F64 => match line.parse::<f64>() {
-                Ok(_) => Ok(line.to_string()),
-                Err(e) => Err(format!(
-                    "In testing the test input {line:?} the parsing to f64 failed with error: {e}"
-                )),
-            },
-            Self::VecI32
-            | Self::VecBool
-            | Self::VecF64
-            | Self::VecVecI32
-            | Self::VecString
-            | Self::VecVecString
-            | Self::VecVecChar => {
-                match Self::does_pass_basic_vec_tests(line) {
-                    Ok(()) => {
-                        let mut result = line.to_string();
-                        if [Self::VecString, Self::VecVecString].contains(self) {
-                            result = result.replace("\",", "\".into(),"); // Replace ones before end
-                            result = result.replace("\"]", "\".into()]"); // Replace end
-                        } else if self == &Self::VecVecChar {
-                            result = result.replace('"', "'");
-                        }
-                        Ok(result.replace('[', "vec!["))
-                    }
-                    Err(e) => Err(e.to_string()),
-                }
-            }
-            Self::List => match Self::does_pass_basic_vec_tests(line) {
-                Ok(()) => Ok(format!("ListHead::from(vec!{line}).into()")),
-                Err(e) => Err(e.to_string()),
-            },
-            Self::Tree => match Self::does_pass_basic_vec_tests(line) {
-                Ok(()) => Ok(format!(r#"TreeRoot::from("{line}").into()"#)),
-                Err(e) => Err(e.to_string()),
-            },
-            Self::Other { raw: _ } => Ok(format!(r#"todo!("{line}")"#)),
-        };
-        result.unwrap_or_else(|e| {
-            warn!("Type Mismatch? Type detected as '{self:?}' but got argument value of {line:?}. Error: {e}");
-            format!("todo!({line:?})")
-        })
-    }
-
-    fn does_pass_basic_vec_tests(s: &str) -> anyhow::Result<()> {
-        if !s.starts_with('[') || !s.ends_with(']') {
-            bail!("Expecting something that can be represented as a vec but got {s:?}");
-        }
-        Ok(())
-    }
-
-    const fn is_tree(&self) -> bool {
-        matches!(self, Self::Tree)
-    }
-
-    const fn is_list(&self) -> bool {
-        matches!(self, Self::List)
-    }
-
-    /// Returns `true` if the function arg type is [`Other`].
-    ///
-    /// [`Other`]: FunctionArgType::Other
-    #[must_use]
-    const fn is_other(&self) -> bool {
-        matches!(self, Self::Other { .. })
-    }
-
-    fn as_str(&self) -> &str {
-        match self {
-            // Search Key: SK_ADD_TYPE
-            // Add string that corresponds to each variant
-            Self::I32 => "i32",
-            Self::I64 => "i64",
-            Self::F64 => "f64",
-            Self::Bool => "bool",
-            Self::String_ => "String",
-            Self::VecI32 => "Vec<i32>",
-            Self::VecF64 => "Vec<f64>",
-            Self::VecBool => "Vec<bool>",
-            Self::VecString => "Vec<String>",
-            Self::VecVecI32 => "Vec<Vec<i32>>",
-            Self::VecVecString => "Vec<Vec<String>>",
-            Self::VecVecChar => "Vec<Vec<char>>",
-            Self::List => "Option<Box<ListNode>>",
-            Self::Tree => "Option<Rc<RefCell<TreeNode>>>",
-            Self::Other { raw } => raw,
-        }
-    }
-}
-
-impl From<&str> for FunctionArgType {
-    fn from(value: &str) -> Self {
-        let value = value.trim();
-        // Loop over all variants and see if one matches
-        for fat in Self::iter() {
-            if !fat.is_other() && fat.as_str() == value {
-                return fat;
-            }
-        }
-        warn!(
-            "Unknown type {value:?} found please report this in an issue https://github.com/rust-practice/cargo-leet/issues/new?&labels=bug&template=missing_type.md"
-        );
-        Self::Other {
-            raw: value.to_string(),
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
-    use std::collections::HashSet;
 
     use crate::tool::core::helpers::local_store::tests::insta_settings;
+    use crate::tool::core::helpers::problem_description::constraints::ConstraintTarget;
     use rstest::rstest;
-    use strum::IntoEnumIterator;
 
     use super::*;
 
@@ -559,6 +1026,258 @@ impl Solution {
         assert!(!problem_code.has_list());
     }
 
+    const fn get_385_mini_parser() -> &'static str {
+        "// #[derive(Debug, PartialEq, Eq)]
+// pub enum NestedInteger {
+//   Int(i32),
+//   List(Vec<NestedInteger>)
+// }
+impl Solution {
+    pub fn deserialize(s: String) -> NestedInteger {
+        
+    }
+}"
+    }
+
+    const fn get_341_flatten_nested_list_iterator() -> &'static str {
+        "// #[derive(Debug, PartialEq, Eq)]
+// pub enum NestedInteger {
+//   Int(i32),
+//   List(Vec<NestedInteger>)
+// }
+struct NestedIterator {
+
+}
+
+impl NestedIterator {
+
+    fn new(nestedList: Vec<NestedInteger>) -> Self {
+        
+    }
+    
+    fn next(&self) -> i32 {
+        
+    }
+    
+    fn has_next(&self) -> bool {
+        
+    }
+}"
+    }
+
+    const fn get_374_guess_number() -> &'static str {
+        "/**
+ * Forward declaration of guess API.
+ * @param  num   your guess
+ * @return 	     -1 if num is higher than the picked number
+ *			      1 if num is lower than the picked number
+ *               otherwise return 0
+ * unsafe fn guess(num: i32) -> i32 {}
+ */
+
+impl Solution {
+    unsafe fn guessNumber(n: i32) -> i32 {
+        
+    }
+}"
+    }
+
+    const fn get_278_first_bad_version() -> &'static str {
+        "// The API isBadVersion is defined for you.
+// isBadVersion(version:i32)-> bool;
+// to call it use self.isBadVersion(version)
+
+impl Solution {
+    pub fn first_bad_version(&self, n: i32) -> i32 {
+		
+    }
+}"
+    }
+
+    const fn get_1095_find_in_mountain_array() -> &'static str {
+        "/**
+ * // This is the MountainArray's API interface.
+ * // You should not implement it, or speculate about its implementation
+ *  struct MountainArray;
+ *  impl MountainArray {
+ *     fn get(index:i32)->i32;
+ *     fn length()->i32;
+ * };
+ */
+
+impl Solution {
+    pub fn find_in_mountain_array(target: i32, mountainArr: &MountainArray) -> i32 {
+        
+    }
+}"
+    }
+
+    const fn get_589_nary_tree_preorder() -> &'static str {
+        "// Definition for a Node.
+// #[derive(Debug, PartialEq, Eq)]
+// pub struct Node {
+//   pub val: i32,
+//   pub children: Vec<Rc<RefCell<Node>>>,
+// }
+use std::rc::Rc;
+use std::cell::RefCell;
+impl Solution {
+    pub fn preorder(root: Option<Rc<RefCell<Node>>>) -> Vec<i32> {
+        
+    }
+}"
+    }
+
+    const fn get_690_employee_importance() -> &'static str {
+        "// Definition for Employee.
+// #[derive(Debug, Clone)]
+// pub struct Employee {
+//   pub id: i32,
+//   pub importance: i32,
+//   pub subordinates: Vec<i32>,
+// }
+impl Solution {
+    pub fn get_importance(employees: Vec<Employee>, id: i32) -> i32 {
+        
+    }
+}"
+    }
+
+    const fn get_1265_print_in_reverse() -> &'static str {
+        "// This is the ImmutableListNode's API interface.
+// You should not implement it, or speculate about its implementation.
+// impl ImmutableListNode {
+//     pub fn printValue(&self);
+//     pub fn getNext(&self) -> Option<&ImmutableListNode>;
+// }
+impl Solution {
+    pub fn print_linked_list_in_reverse(head: &ImmutableListNode) {
+        
+    }
+}"
+    }
+
+    /// Defines its own `Node` which should not be imported
+    const fn get_node_defined_in_code() -> &'static str {
+        "pub struct Node {
+    pub val: i32,
+}
+impl Solution {
+    pub fn sum(nodes: Vec<Node>) -> i32 {
+        
+    }
+}"
+    }
+
+    #[rstest]
+    #[case(get_385_mini_parser(), vec!["NestedInteger"])]
+    #[case(get_341_flatten_nested_list_iterator(), vec!["NestedInteger"])]
+    #[case(get_97_interleaving_string(), vec![])]
+    #[case(get_374_guess_number(), vec!["guess"])]
+    #[case(get_278_first_bad_version(), vec!["VersionControl"])]
+    #[case(get_1095_find_in_mountain_array(), vec!["MountainArray"])]
+    #[case(get_589_nary_tree_preorder(), vec!["Node"])]
+    #[case(get_690_employee_importance(), vec!["Employee"])]
+    #[case(get_1265_print_in_reverse(), vec!["ImmutableListNode"])]
+    #[case(get_node_defined_in_code(), vec![])]
+    fn helper_types_imported(#[case] code: &str, #[case] expected: Vec<&str>) {
+        let problem_code: ProblemCode = code.to_string().try_into().expect("Should be valid code");
+        assert_eq!(problem_code.get_cargo_leet_imports(), expected);
+    }
+
+    #[rstest]
+    #[case::nested_integer(
+        get_385_mini_parser(),
+        r#""[123,[456,[789]]]""#,
+        "[123,[456,[789]]]",
+        r#""[123,[456,[789]]]", NestedInteger::from("[123,[456,[789]]]")"#
+    )]
+    #[case::nary_tree(
+        get_589_nary_tree_preorder(),
+        "[1,null,3,2,4,null,5,6]",
+        "[1,3,5,6,2,4]",
+        r#"cargo_leet::NaryTreeRoot::from("[1,null,3,2,4,null,5,6]").into(), vec![1,3,5,6,2,4]"#
+    )]
+    #[case::employee(
+        get_690_employee_importance(),
+        "[[1,5,[2,3]],[2,3,[]],[3,3,[]]]\n1",
+        "11",
+        "vec![Employee::new(1, 5, vec![2,3]),Employee::new(2, 3, vec![]),Employee::new(3, 3, vec![])], 1, 11"
+    )]
+    #[case::immutable_list(
+        get_1265_print_in_reverse(),
+        "[1,2,3,4]",
+        "[4,3,2,1]",
+        "ImmutableListNode::from(vec![1,2,3,4]), vec![4,3,2,1]"
+    )]
+    fn helper_type_test_case(
+        #[case] code: &str,
+        #[case] input: &str,
+        #[case] solution: &str,
+        #[case] expected: &str,
+    ) {
+        let fn_info = extract_function_info(code);
+        assert_eq!(fn_info.get_test_case(input, solution, true), expected);
+    }
+
+    #[test]
+    fn printed_values_checked() {
+        let fn_info = extract_function_info(get_1265_print_in_reverse());
+        assert_eq!(
+            fn_info.get_args_with_case(),
+            "#[case] head: ImmutableListNode, #[case] expected: Vec<i32>"
+        );
+        assert_eq!(
+            fn_info.get_solution_check_code(None),
+            "Solution::print_linked_list_in_reverse(&head);\n        let actual = cargo_leet::take_printed_values();\n        assert_eq!(actual, expected);"
+        );
+    }
+
+    #[rstest]
+    #[case::guess_number(
+        get_374_guess_number(),
+        "10\n6",
+        "6",
+        "10, 6, 6",
+        "#[case] n: i32, #[case] pick: i32, #[case] expected: i32",
+        "cargo_leet::set_secret(pick);
+        let actual = unsafe { Solution::guessNumber(n) };
+        assert_eq!(actual, expected);"
+    )]
+    #[case::first_bad_version(
+        get_278_first_bad_version(),
+        "5\n4",
+        "4",
+        "5, 4, 4",
+        "#[case] n: i32, #[case] bad: i32, #[case] expected: i32",
+        "cargo_leet::set_secret(bad);
+        let actual = Solution.first_bad_version(n);
+        assert_eq!(actual, expected);"
+    )]
+    #[case::mountain_array_listed_first(
+        get_1095_find_in_mountain_array(),
+        "[1,2,3,4,5,3,1]\n3",
+        "2",
+        "3, MountainArray::new(vec![1,2,3,4,5,3,1]), 2",
+        "#[case] target: i32, #[case] mountainArr: MountainArray, #[case] expected: i32",
+        "let actual = Solution::find_in_mountain_array(target, &mountainArr);
+        assert_eq!(actual, expected);"
+    )]
+    fn interactive(
+        #[case] code: &str,
+        #[case] example: &str,
+        #[case] solution: &str,
+        #[case] test_case: &str,
+        #[case] args_with_case: &str,
+        #[case] check_code: &str,
+    ) {
+        let fn_info = extract_function_info(code);
+        assert_eq!(fn_info.get_test_case(example, solution, true), test_case);
+        assert_eq!(fn_info.get_args_with_case(), args_with_case);
+        assert_eq!(fn_info.get_solution_check_code(None), check_code);
+        assert!(fn_info.get_stress_test().is_none() || fn_info.judge_api.is_none());
+    }
+
     #[test]
     fn get_args_with_case() {
         // Arrange / Act
@@ -567,7 +1286,7 @@ impl Solution {
         // Assert
         assert_eq!(
             fn_info.get_args_with_case(),
-            "#[case] s1: String, #[case]  s2: String, #[case]  s3: String, #[case] expected: bool"
+            "#[case] s1: String, #[case] s2: String, #[case] s3: String, #[case] expected: bool"
         );
     }
 
@@ -585,24 +1304,63 @@ impl Solution {
     /// actual problem name
     fn get_fn_info_3224_min_changes() -> FunctionInfo {
         FunctionInfo {
+            output_order: OutputOrder::Exact,
             name: "min_changes".into(),
             fn_args: FunctionArgs {
-                raw_str: "nums: Vec<i32>, k: i32".into(),
                 args: vec![
                     FunctionArg {
                         identifier: "nums".into(),
-                        arg_type: FunctionArgType::VecI32,
+                        arg_type: "Vec<i32>".into(),
+                        is_mut_ref: false,
+                        is_ref: false,
+                        constraints: vec![],
                     },
                     FunctionArg {
                         identifier: "k".into(),
-                        arg_type: FunctionArgType::I32,
+                        arg_type: "i32".into(),
+                        is_mut_ref: false,
+                        is_ref: false,
+                        constraints: vec![],
                     },
                 ],
             },
-            return_type: Some(FunctionArgType::I32),
+            return_type: Some("i32".into()),
+            printed_output: None,
+            judge_api: None,
+            is_unsafe: false,
+            is_method: false,
+            checker: None,
         }
     }
 
+    #[rstest]
+    fn get_stress_test_with_constraints(insta_settings: insta::Settings) {
+        let constraint = |identifier: &str, target, max| Constraint {
+            identifier: Some(identifier.into()),
+            target,
+            min: Some(0),
+            max: Some(max),
+            is_max_relative: false,
+            characters: None,
+        };
+        let mut problem_code = ProblemCode {
+            code: String::new(),
+            type_: ProblemType::NonDesign(get_fn_info_3224_min_changes()),
+        };
+        // No constraint for the elements of nums so a TODO is expected
+        problem_code.add_constraints(&[
+            constraint("nums", ConstraintTarget::Length, 100_000),
+            constraint("k", ConstraintTarget::Value, 100_000),
+            constraint("other", ConstraintTarget::Value, 5),
+        ]);
+        let ProblemType::NonDesign(fn_info) = problem_code.type_ else {
+            unreachable!("constructed as non design above")
+        };
+        insta_settings.bind(|| {
+            insta::assert_snapshot!(fn_info.get_stress_test().unwrap());
+        });
+    }
+
     #[test]
     fn get_test_case_ok() {
         // Arrange
@@ -612,7 +1370,7 @@ impl Solution {
         let solution = "2";
 
         // Act
-        let actual = fn_info.get_test_case(input, solution);
+        let actual = fn_info.get_test_case(input, solution, true);
 
         // Assert
         assert_eq!(actual, expected);
@@ -626,7 +1384,7 @@ impl Solution {
         let solution = "2";
 
         // Act
-        let actual = fn_info.get_test_case(input, solution);
+        let actual = fn_info.get_test_case(input, solution, true);
 
         // Assert
         insta_settings.bind(|| {
@@ -634,54 +1392,174 @@ impl Solution {
         });
     }
 
-    const fn create_code_stub_all_arg_types_non_design() -> &'static str {
-        // Search Key: SK_ADD_TYPE
-        // Add a unique argument to the function to test retrieval of the unique
-        // argument name (should match the lookup area)
-        "
-impl Solution {
-    pub fn func_name(
-        L2AC6p: i32,
-        q7kv5k: i64,
-        pP7GhC: f64,
-        HFGzdD: bool,
-        kjACSr: String,
-        ePfFj3: Vec<i32>,
-        kRubF2: Vec<f64>,
-        ykyF5X: Vec<bool>,
-        NkCeR6: Vec<String>,
-        bBtcWe: Vec<Vec<i32>>,
-        ndi4ny: Vec<Vec<String>>,
-        ndi9ny: Vec<Vec<char>>,
-        bJy3HH: Option<Box<ListNode>>,
-        ndQLTu: Option<Rc<RefCell<TreeNode>>>,
-        PRnJhw: UnknownType,
+    #[rstest]
+    #[case("Vec<Vec<i32>>", OutputOrder::Any, Some("any_order_nested"))]
+    #[case("Vec<Vec<i32>>", OutputOrder::AnyOuter, Some("any_order"))]
+    #[case("Vec<String>", OutputOrder::Any, Some("any_order"))]
+    #[case("Vec<String>", OutputOrder::Exact, None)]
+    #[case("i32", OutputOrder::Any, None)]
+    fn solution_comparison_code(
+        #[case] return_type: &str,
+        #[case] output_order: OutputOrder,
+        #[case] helper: Option<&str>,
     ) {
+        let mut fn_info = extract_function_info(&format!(
+            "impl Solution {{\n    pub fn f(x: i32) -> {return_type} {{\n        todo!()\n    }}\n}}"
+        ));
+        fn_info.output_order = output_order;
+        let expected = helper.map_or_else(
+            || "assert_eq!(actual, expected);".to_string(),
+            |helper| {
+                format!("assert_eq!(cargo_leet::{helper}(actual), cargo_leet::{helper}(expected));")
+            },
+        );
+        assert_eq!(fn_info.get_solution_comparison_code(None), expected);
     }
-}
-"
+
+    #[rstest]
+    #[case("f64")]
+    #[case("Vec<f64>")]
+    #[case("Vec<Vec<f64>>")]
+    #[case("Vec<Option<f64>>")]
+    fn float_comparison_code(#[case] return_type: &str) {
+        let fn_info = extract_function_info(&format!(
+            "impl Solution {{\n    pub fn f(x: i32) -> {return_type} {{\n        todo!()\n    }}\n}}"
+        ));
+        assert_eq!(
+            fn_info.get_solution_comparison_code(None),
+            "cargo_leet::assert_approx_eq!(actual, expected);"
+        );
     }
 
-    const fn fn_type_to_id(fat: &FunctionArgType) -> &'static str {
-        match fat {
-            // Search Key: SK_ADD_TYPE
-            // Add the unique string value as an id (needs to match area where it is set)
-            FunctionArgType::I32 => "L2AC6p",
-            FunctionArgType::I64 => "q7kv5k",
-            FunctionArgType::F64 => "pP7GhC",
-            FunctionArgType::Bool => "HFGzdD",
-            FunctionArgType::String_ => "kjACSr",
-            FunctionArgType::VecI32 => "ePfFj3",
-            FunctionArgType::VecF64 => "kRubF2",
-            FunctionArgType::VecBool => "ykyF5X",
-            FunctionArgType::VecString => "NkCeR6",
-            FunctionArgType::VecVecI32 => "bBtcWe",
-            FunctionArgType::VecVecString => "ndi4ny",
-            FunctionArgType::VecVecChar => "ndi9ny",
-            FunctionArgType::List => "bJy3HH",
-            FunctionArgType::Tree => "ndQLTu",
-            FunctionArgType::Other { .. } => "PRnJhw",
-        }
+    #[rstest]
+    #[case("i32", "assert_eq!(actual, expected, \"example {example}\");")]
+    #[case(
+        "f64",
+        "cargo_leet::assert_approx_eq!(actual, expected, cargo_leet::DEFAULT_APPROX_TOLERANCE, \"example {example}\");"
+    )]
+    fn comparison_code_names_example(#[case] return_type: &str, #[case] expected: &str) {
+        let fn_info = extract_function_info(&format!(
+            "impl Solution {{\n    pub fn f(x: i32) -> {return_type} {{\n        todo!()\n    }}\n}}"
+        ));
+        assert_eq!(
+            fn_info.get_solution_comparison_code(Some("example")),
+            expected
+        );
+    }
+
+    const fn get_189_rotate_array() -> &'static str {
+        "impl Solution {
+    pub fn rotate(nums: &mut Vec<i32>, k: i32) {
+        
+    }
+}"
+    }
+
+    #[test]
+    fn mutated_argument_checked() {
+        let fn_info = extract_function_info(get_189_rotate_array());
+        assert!(fn_info.fn_args.args[0].is_mut_ref);
+        assert_eq!(fn_info.fn_args.args[0].arg_type.to_string(), "Vec<i32>");
+        assert!(!fn_info.fn_args.args[1].is_mut_ref);
+        assert_eq!(
+            fn_info.get_args_with_case(),
+            "#[case] mut nums: Vec<i32>, #[case] k: i32, #[case] expected: Vec<i32>"
+        );
+        assert_eq!(
+            fn_info.get_args_with_types(),
+            "mut nums: Vec<i32>, k: i32, expected: Vec<i32>"
+        );
+        assert_eq!(
+            fn_info.get_test_case("[1,2,3,4,5,6,7]\n3", "[5,6,7,1,2,3,4]", true),
+            "vec![1,2,3,4,5,6,7], 3, vec![5,6,7,1,2,3,4]"
+        );
+        assert_eq!(
+            fn_info.get_solution_check_code(None),
+            "Solution::rotate(&mut nums, k);\n        let actual = nums;\n        assert_eq!(actual, expected);"
+        );
+    }
+
+    #[rstest]
+    fn mutated_argument_generated_tests(insta_settings: insta::Settings) {
+        let fn_info = extract_function_info(get_189_rotate_array());
+        let actual = format!(
+            "{}\n\n{}",
+            fn_info.get_stress_test().unwrap(),
+            fn_info.get_fuzz_test().unwrap()
+        );
+        insta_settings.bind(|| {
+            insta::assert_snapshot!(actual);
+        });
+    }
+
+    #[rstest]
+    #[case::custom(
+        "find_peak",
+        "impl Solution {\n    pub fn find_peak_element(nums: Vec<i32>) -> i32 {\n    }\n}",
+        Checker::Custom
+    )]
+    #[case::permutation(
+        "permutation",
+        "impl Solution {\n    pub fn find_duplicates(nums: Vec<i32>) -> Vec<i32> {\n    }\n}",
+        Checker::IsPermutationOf
+    )]
+    #[case::sorted_subsequence(
+        "sorted_subsequence",
+        "impl Solution {\n    pub fn largest_divisible_subset(nums: Vec<i32>) -> Vec<i32> {\n    }\n}",
+        Checker::IsSortedSubsequenceOf
+    )]
+    #[case::mutated_argument(
+        "mutated_argument",
+        "impl Solution {\n    pub fn wiggle_sort(nums: &mut Vec<i32>) {\n    }\n}",
+        Checker::Custom
+    )]
+    #[case::path_in_grid(
+        "path_in_grid",
+        "impl Solution {\n    pub fn shortest_path(grid: Vec<Vec<char>>, k: i32) -> Vec<Vec<i32>> {\n    }\n}",
+        Checker::IsValidPathInGrid
+    )]
+    fn checker_used(
+        #[case] name: &str,
+        #[case] code: &str,
+        #[case] checker: Checker,
+        insta_settings: insta::Settings,
+    ) {
+        let mut problem_code: ProblemCode = code.to_string().try_into().unwrap();
+        problem_code.set_checker(CheckerConfig {
+            checker,
+            is_user_defined: false,
+        });
+        let ProblemType::NonDesign(fn_info) = problem_code.type_ else {
+            unreachable!("constructed as non design above")
+        };
+        assert!(fn_info.get_fuzz_test().is_none());
+        let actual = format!(
+            "{}\n\n{}",
+            fn_info.get_solution_check_code(None),
+            fn_info.get_check_answer_fn().unwrap()
+        );
+        insta_settings.bind(|| {
+            insta::assert_snapshot!(format!("checker {name}"), actual);
+        });
+    }
+
+    #[test]
+    fn checker_user_defined() {
+        let mut problem_code: ProblemCode =
+            get_97_interleaving_string().to_string().try_into().unwrap();
+        problem_code.set_checker(CheckerConfig {
+            checker: Checker::Custom,
+            is_user_defined: true,
+        });
+        let ProblemType::NonDesign(fn_info) = problem_code.type_ else {
+            unreachable!("constructed as non design above")
+        };
+        assert!(fn_info.get_check_answer_fn().is_none());
+        assert!(
+            fn_info
+                .get_solution_check_code(None)
+                .contains("check_answer(&s1, &s2, &s3, &actual, &expected)")
+        );
     }
 
     fn extract_function_info(code: &str) -> FunctionInfo {
@@ -697,123 +1575,51 @@ impl Solution {
     #[test]
     fn function_parsing() {
         // Arrange
-        let code = create_code_stub_all_arg_types_non_design();
-
-        // Create hashset and fill with the possible argument types
-        let mut left_to_see = HashSet::new();
-        FunctionArgType::iter().for_each(|x| {
-            left_to_see.insert(x);
-        });
-
-        // Add special handling for Other variant
-        left_to_see.remove(&FunctionArgType::Other { raw: String::new() });
-        left_to_see.insert(FunctionArgType::Other {
-            raw: "UnknownType".to_string(),
-        });
+        let code = "
+impl Solution {
+    // Not the function: pub fn other(x: i32) -> i32 {
+    pub fn func_name<'a, T>(
+        &self,
+        a: i32,
+        b: Vec<Vec<Vec<Vec<char>>>>,
+        c: &mut Vec<Option<Rc<RefCell<TreeNode>>>>,
+        d: UnknownType,
+        e: HashMap<i32, Vec<T>>,
+    ) -> impl Iterator<Item = i32> + 'a
+    where
+        T: Ord,
+    {
+    }
+}
+";
 
         // Act
         let fn_info = extract_function_info(code);
 
         // Assert
         assert_eq!(fn_info.name, "func_name");
-        assert!(fn_info.return_type.is_none());
-        for arg in &fn_info.fn_args.args {
-            assert!(
-                left_to_see.contains(&arg.arg_type),
-                "Duplicate type seen. Each type should show up EXACTLY ONCE. Duplicate type: {}",
-                arg.arg_type.as_str()
-            );
-            left_to_see.remove(&arg.arg_type);
-            assert_eq!(
-                arg.identifier,
-                fn_type_to_id(&arg.arg_type),
-                "ArgType: {}",
-                arg.arg_type.as_str()
-            );
-        }
-        assert!(
-            left_to_see.is_empty(),
-            "Expected all argument types to be seen but haven't seen {left_to_see:?}",
+        assert_eq!(
+            fn_info.return_type.as_ref().unwrap().to_string(),
+            "impl Iterator<Item=i32>+'a"
         );
-    }
-
-    #[test]
-    fn function_arg_type_apply() {
-        // Using an array instead of rstest because we need to ensure all inputs are
-        // covered
-        use FunctionArgType as FAT;
-        let inputs = [
-            // Search Key: SK_ADD_TYPE
-            // Create a pair for the new type with a sample input from leetcode
-            (FAT::I32, "1"),
-            (FAT::I64, "2"),
-            (FAT::F64, "2.00000"),
-            (FAT::Bool, "true"),
-            (FAT::String_, r#""leetcode""#),
-            (FAT::VecI32, "[1,2,3,4]"),
-            (FAT::VecF64, "[6.00000,0.50000,-1.00000,1.00000,-1.00000]"),
-            (FAT::VecBool, "[true,false,false,false,false]"),
-            (FAT::VecString, r#"["@..aA","..B#.","....b"]"#),
-            (FAT::VecVecI32, "[[2,2,3],[7]]"),
-            (
-                FAT::VecVecString,
-                r#"[["java"],["nodejs"],["nodejs","reactjs"]]"#,
-            ),
-            (
-                FAT::VecVecChar,
-                r#"[["X",".",".","X"],[".",".",".","X"],[".",".",".","X"]]"#,
-            ),
-            (FAT::List, "[1,2,4]"),
-            (FAT::Tree, "[1,null,2,3]"),
-            (FAT::Other { raw: String::new() }, "1"),
-        ];
-
-        // Create hashset and fill with the possible argument types
-        let mut left_to_see = HashSet::new();
-        FunctionArgType::iter().for_each(|x| {
-            left_to_see.insert(x);
-        });
-
-        // Ensure each is there exactly once
-        for (fat, _) in &inputs {
-            assert!(
-                left_to_see.contains(fat),
-                "Duplicate type seen. Each type should show up EXACTLY ONCE. Duplicate type: {}",
-                fat.as_str()
-            );
-            left_to_see.remove(fat);
-        }
-        assert!(
-            left_to_see.is_empty(),
-            "Expected all argument types to be seen but haven't seen {left_to_see:?}",
+        let args: Vec<_> = fn_info
+            .fn_args
+            .args
+            .iter()
+            .map(|arg| format!("{}: {}", arg.identifier, arg.arg_type))
+            .collect();
+        assert_eq!(
+            args,
+            [
+                "a: i32",
+                "b: Vec<Vec<Vec<Vec<char>>>>",
+                "c: Vec<Option<Rc<RefCell<TreeNode>>>>",
+                "d: UnknownType",
+                "e: HashMap<i32,Vec<T>>",
+            ]
         );
-
-        for (fat, input) in inputs {
-            let expected = match fat {
-                // Search Key: SK_ADD_TYPE
-                // Add the expected output of the input set
-                FAT::I32 => "1",
-                FAT::I64 => "2",
-                FAT::F64 => "2.00000",
-                FAT::Bool => "true",
-                FAT::String_ => r#""leetcode""#,
-                FAT::VecI32 => "vec![1,2,3,4]",
-                FAT::VecF64 => "vec![6.00000,0.50000,-1.00000,1.00000,-1.00000]",
-                FAT::VecBool => "vec![true,false,false,false,false]",
-                FAT::VecString => r#"vec!["@..aA".into(),"..B#.".into(),"....b".into()]"#,
-                FAT::VecVecI32 => "vec![vec![2,2,3],vec![7]]",
-                FAT::VecVecString => {
-                    r#"vec![vec!["java".into()],vec!["nodejs".into()],vec!["nodejs".into(),"reactjs".into()]]"#
-                }
-                FAT::VecVecChar => {
-                    "vec![vec!['X','.','.','X'],vec!['.','.','.','X'],vec!['.','.','.','X']]"
-                }
-                FAT::List => "ListHead::from(vec![1,2,4]).into()",
-                FAT::Tree => r#"TreeRoot::from("[1,null,2,3]").into()"#,
-                FAT::Other { raw: _ } => r#"todo!("1")"#,
-            };
-            let actual = fat.apply(input);
-            assert_eq!(actual, expected);
-        }
+        assert!(fn_info.fn_args.args[2].is_mut_ref);
+        assert!(fn_info.has_tree());
+        assert!(!fn_info.has_list());
     }
 }
@@ -0,0 +1,856 @@
+//! Types of the arguments and return values of the solution function
+//!
+//! Types are represented recursively (for example `Vec<Vec<char>>` is a vec of
+//! a vec of a primitive) so any nesting works without listing each combination
+
+use log::{debug, warn};
+use serde_json::Value;
+use std::fmt::{Display, Write as _};
+
+use super::{
+    super::problem_description::constraints::{Constraint, ConstraintTarget},
+    helper_type::HelperType,
+};
+
+/// Function Arg Type (FAT)
+#[derive(Debug, Eq, Hash, PartialEq)]
+pub(super) enum FunctionArgType {
+    Primitive(Primitive),
+    Vec(Box<Self>),
+    Option(Box<Self>),
+    /// `Option<Box<ListNode>>`
+    List,
+    /// `Option<Rc<RefCell<TreeNode>>>`
+    Tree,
+    /// Type defined by leetcode for the problem and provided by `cargo_leet`
+    Helper(HelperType),
+    Other {
+        raw: String,
+    },
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, Clone, Copy)]
+pub(super) enum Primitive {
+    I32,
+    I64,
+    U32,
+    F64,
+    Bool,
+    Char,
+    String_,
+}
+
+impl Primitive {
+    const ALL: [Self; 7] = [
+        Self::I32,
+        Self::I64,
+        Self::U32,
+        Self::F64,
+        Self::Bool,
+        Self::Char,
+        Self::String_,
+    ];
+
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::I32 => "i32",
+            Self::I64 => "i64",
+            Self::U32 => "u32",
+            Self::F64 => "f64",
+            Self::Bool => "bool",
+            Self::Char => "char",
+            Self::String_ => "String",
+        }
+    }
+}
+
+const LIST: &str = "Option<Box<ListNode>>";
+const TREE: &str = "Option<Rc<RefCell<TreeNode>>>";
+
+impl FunctionArgType {
+    /// Converts the value of an example into the rust expression used in the
+    /// generated code, `name` describes the value in errors (e.g. "argument
+    /// `grid`")
+    ///
+    /// See [`super::FunctionInfo::get_test_case`] for `is_rstest`
+    pub(super) fn apply(&self, name: &str, line: &str, is_rstest: bool) -> String {
+        debug!("Going to apply changes to argument input for {self:#?} to {line:?}");
+        let line = line.trim();
+        self.convert(name, line, is_rstest).unwrap_or_else(|e| {
+            warn!("Type Mismatch? {e}");
+            format!("todo!({line:?})")
+        })
+    }
+
+    /// Returns `true` if `line` can be converted into a value of this type
+    pub(super) fn accepts(&self, line: &str) -> bool {
+        self.convert("", line.trim(), false).is_ok()
+    }
+
+    /// Parses `line` as json and converts it into a rust expression
+    ///
+    /// See [`Self::apply`]
+    fn convert(&self, name: &str, line: &str, is_rstest: bool) -> Result<String, String> {
+        if let Self::Other { raw: _ } = self {
+            return Ok(format!("todo!({line:?})"));
+        }
+        let value: Value = serde_json::from_str(line)
+            .map_err(|e| format!("{name} with value {line:?} is not valid json: {e}"))?;
+        match (self, &value) {
+            // rstest converts string literals into `String` itself
+            (Self::Primitive(Primitive::String_), Value::String(s)) if is_rstest => {
+                Ok(format!("{s:?}"))
+            }
+            _ => self
+                .to_rust(&value, &mut vec![])
+                .map_err(|e| format!("{name} expected {self} but {e}")),
+        }
+    }
+
+    /// Recursively converts `value` into a rust expression of this type
+    ///
+    /// `path` holds the indices of `value` in the outer arrays and the error
+    /// describes what was found there
+    fn to_rust(&self, value: &Value, path: &mut Vec<usize>) -> Result<String, String> {
+        let mismatch = |path: &[usize], details: &str| {
+            let location = if path.is_empty() {
+                "the value".to_string()
+            } else {
+                let mut result = "element ".to_string();
+                for i in path {
+                    write!(result, "[{i}]").expect("write! macro failed");
+                }
+                result
+            };
+            Err(format!("{location} is {}{details}", describe(value)))
+        };
+        match (self, value) {
+            (Self::Primitive(primitive), _) => match (primitive, value) {
+                (Primitive::I32, Value::Number(n)) if n.as_i64().is_some_and(is_i32) => {
+                    Ok(n.to_string())
+                }
+                (Primitive::I64, Value::Number(n)) if n.is_i64() => Ok(n.to_string()),
+                (Primitive::U32, Value::Number(n)) if n.as_u64().is_some_and(is_u32) => {
+                    Ok(n.to_string())
+                }
+                (Primitive::I32 | Primitive::I64 | Primitive::U32, Value::Number(_)) => mismatch(
+                    path,
+                    &format!(" which does not fit in {}", primitive.as_str()),
+                ),
+                (Primitive::F64, Value::Number(n)) => {
+                    let n = n.as_f64().expect("json numbers are finite");
+                    Ok(format!("{n:?}"))
+                }
+                (Primitive::Bool, Value::Bool(b)) => Ok(b.to_string()),
+                (Primitive::Char, Value::String(s)) => {
+                    let mut chars = s.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) => Ok(format!("{c:?}")),
+                        _ => mismatch(path, " instead of a single character"),
+                    }
+                }
+                (Primitive::String_, Value::String(s)) => Ok(format!("{s:?}.into()")),
+                _ => mismatch(path, ""),
+            },
+            (Self::Vec(inner), Value::Array(items)) => {
+                let mut result = Vec::with_capacity(items.len());
+                for (i, item) in items.iter().enumerate() {
+                    path.push(i);
+                    result.push(inner.to_rust(item, path)?);
+                    path.pop();
+                }
+                Ok(format!("vec![{}]", result.join(",")))
+            }
+            (Self::Option(_), Value::Null) => Ok("None".to_string()),
+            (Self::Option(inner), _) => Ok(format!("Some({})", inner.to_rust(value, path)?)),
+            (Self::List | Self::Tree, Value::Array(items)) => {
+                let mut values = Vec::with_capacity(items.len());
+                for (i, item) in items.iter().enumerate() {
+                    path.push(i);
+                    // Trees use null for missing nodes
+                    values.push(if self == &Self::Tree && item.is_null() {
+                        "null".to_string()
+                    } else {
+                        Self::Primitive(Primitive::I32).to_rust(item, path)?
+                    });
+                    path.pop();
+                }
+                let values = values.join(",");
+                if self == &Self::List {
+                    Ok(format!("ListHead::from(vec![{values}]).into()"))
+                } else {
+                    Ok(format!(r#"TreeRoot::from("[{values}]").into()"#))
+                }
+            }
+            (Self::Helper(helper), _) => {
+                helper.to_rust(value).map_or_else(|| mismatch(path, ""), Ok)
+            }
+            (Self::Other { raw: _ }, _) => mismatch(path, " which cannot be converted"),
+            _ => mismatch(path, ""),
+        }
+    }
+
+    /// Code that builds the largest value allowed by `constraints`
+    ///
+    /// Limits not found in the constraints are replaced with a small default
+    /// and a TODO comment. Returns `None` for types that cannot be built
+    pub(super) fn get_maximal_value_code(&self, constraints: &[Constraint]) -> Option<String> {
+        let mut bounds = Bounds::new(constraints);
+        let code = self.maximal_value(0, &mut bounds)?;
+        Some(bounds.with_todo(code, "No upper bound found in the constraints for"))
+    }
+
+    /// `depth` is the number of collections (vecs, strings, lists or trees)
+    /// this value is nested in
+    fn maximal_value(&self, depth: usize, bounds: &mut Bounds) -> Option<String> {
+        let value_target = if depth == 0 {
+            ConstraintTarget::Value
+        } else {
+            ConstraintTarget::Element
+        };
+        Some(match self {
+            Self::Primitive(primitive) => match primitive {
+                Primitive::I32 => clamp_i32(bounds.max(value_target)).to_string(),
+                Primitive::I64 => bounds.max(value_target).to_string(),
+                Primitive::U32 => bounds
+                    .max(value_target)
+                    .clamp(0, u32::MAX.into())
+                    .to_string(),
+                Primitive::F64 => format!("{}.0", bounds.max(value_target)),
+                Primitive::Bool => "true".to_string(),
+                Primitive::Char => {
+                    let character = bounds.characters("a").chars().next().unwrap_or('a');
+                    format!("{character:?}")
+                }
+                Primitive::String_ => {
+                    let len = bounds.max_len(depth);
+                    bounds.repeated_character(len)
+                }
+            },
+            Self::Vec(inner) => {
+                let element = inner.maximal_value(depth + 1, bounds)?;
+                let len = bounds.max_len(depth).unwrap_or(1);
+                if inner.is_clone() {
+                    format!("vec![{element}; {len}]")
+                } else {
+                    // Nodes are not `Clone` so each item is built separately
+                    format!("(0..{len}).map(|_| {element}).collect()")
+                }
+            }
+            Self::Option(inner) => format!("Some({})", inner.maximal_value(depth, bounds)?),
+            Self::List | Self::Tree => {
+                let element = clamp_i32(bounds.max(ConstraintTarget::Element));
+                let len = bounds.max_len(depth).unwrap_or(1);
+                let root = if self == &Self::List {
+                    "ListHead"
+                } else {
+                    "TreeRoot"
+                };
+                format!("{root}::from(vec![{element}; {len}]).into()")
+            }
+            Self::Helper(..) | Self::Other { .. } => return None,
+        })
+    }
+
+    /// Code that generates a small random value using the `cargo_leet::Generator`
+    /// named `g`
+    ///
+    /// Values and lengths are kept within the constraints but limited to a
+    /// small window so the brute force solution stays fast and failures are
+    /// easy to read. Limits not found in the constraints are replaced with a
+    /// small default and a TODO comment. Returns `None` for types that cannot
+    /// be generated
+    pub(super) fn get_random_value_code(&self, constraints: &[Constraint]) -> Option<String> {
+        let mut bounds = Bounds::new(constraints);
+        let code = self.random_value(0, &mut bounds)?;
+        Some(bounds.with_todo(code, "Bounds not found in the constraints for"))
+    }
+
+    /// See [`Self::maximal_value`] for `depth`
+    fn random_value(&self, depth: usize, bounds: &mut Bounds) -> Option<String> {
+        let value_target = if depth == 0 {
+            ConstraintTarget::Value
+        } else {
+            ConstraintTarget::Element
+        };
+        Some(match self {
+            Self::Primitive(primitive) => match primitive {
+                Primitive::I32 => format!("g.i32({})", bounds.range(value_target, false)),
+                Primitive::I64 => format!("g.i64({})", bounds.range(value_target, false)),
+                Primitive::U32 => format!("g.u32({})", bounds.range(value_target, true)),
+                Primitive::F64 => {
+                    format!("f64::from(g.i32({}))", bounds.range(value_target, false))
+                }
+                Primitive::Bool => "g.bool()".to_string(),
+                Primitive::Char => format!("g.char({:?})", bounds.characters("abc")),
+                Primitive::String_ => {
+                    let len_range = bounds.len_range(depth);
+                    format!("g.string({len_range}, {:?})", bounds.characters("abc"))
+                }
+            },
+            Self::Vec(inner) => {
+                // Grids of values are kept rectangular as most problems expect it
+                if let (0, Self::Vec(element)) = (depth, &**inner)
+                    && element.is_primitive()
+                {
+                    format!(
+                        "g.grid({}, {}, |g| {})",
+                        bounds.len_range(0),
+                        bounds.len_range(1),
+                        element.random_value(2, bounds)?
+                    )
+                } else {
+                    format!(
+                        "g.vec({}, |g| {})",
+                        bounds.len_range(depth),
+                        inner.random_value(depth + 1, bounds)?
+                    )
+                }
+            }
+            Self::Option(inner) => {
+                format!("g.bool().then(|| {})", inner.random_value(depth, bounds)?)
+            }
+            Self::List | Self::Tree => format!(
+                "g.{}({}, {})",
+                if self == &Self::List { "list" } else { "tree" },
+                bounds.len_range(depth),
+                bounds.range(ConstraintTarget::Element, false)
+            ),
+            Self::Helper(..) | Self::Other { .. } => return None,
+        })
+    }
+
+    /// Returns true if values of the type are `Copy`
+    pub(super) const fn is_copy(&self) -> bool {
+        matches!(self, Self::Primitive(primitive) if !matches!(primitive, Primitive::String_))
+    }
+
+    /// Returns true for a vec of vecs (e.g. `Vec<Vec<char>>`)
+    pub(super) fn is_grid(&self) -> bool {
+        matches!(self, Self::Vec(inner) if matches!(**inner, Self::Vec(_)))
+    }
+
+    const fn is_primitive(&self) -> bool {
+        matches!(self, Self::Primitive(..))
+    }
+
+    /// Returns true if values of the type can be copied with `vec![value; n]`
+    fn is_clone(&self) -> bool {
+        match self {
+            Self::Primitive(..) => true,
+            Self::Vec(inner) | Self::Option(inner) => inner.is_clone(),
+            Self::List | Self::Tree | Self::Helper(..) | Self::Other { .. } => false,
+        }
+    }
+
+    /// Returns true if values of the type implement [`Ord`]
+    fn is_ord(&self) -> bool {
+        match self {
+            Self::Primitive(primitive) => primitive != &Primitive::F64,
+            Self::Vec(inner) | Self::Option(inner) => inner.is_ord(),
+            Self::List | Self::Tree | Self::Helper(..) | Self::Other { .. } => false,
+        }
+    }
+
+    /// Returns true if the type is or contains `target`
+    fn contains(&self, target: &Self) -> bool {
+        self == target
+            || match self {
+                Self::Vec(inner) | Self::Option(inner) => inner.contains(target),
+                _ => false,
+            }
+    }
+
+    pub(super) fn is_tree(&self) -> bool {
+        self.contains(&Self::Tree)
+    }
+
+    pub(super) fn is_list(&self) -> bool {
+        self.contains(&Self::List)
+    }
+
+    /// Type of the values printed through this type that are checked in place
+    /// of a return value, see [`HelperType::printed_type`]
+    pub(super) fn printed_type(&self) -> Option<Self> {
+        match self {
+            Self::Helper(helper) => helper.printed_type().map(Self::from),
+            _ => None,
+        }
+    }
+
+    /// Returns true for `f64` and vecs or options of it, which are compared
+    /// within a tolerance
+    pub(super) fn has_f64(&self) -> bool {
+        self.contains(&Self::Primitive(Primitive::F64))
+    }
+
+    /// Returns true for vecs of items that implement [`Ord`]
+    pub(super) fn is_sortable_vec(&self) -> bool {
+        matches!(self, Self::Vec(inner) if inner.is_ord())
+    }
+
+    /// Returns true for vecs of vecs of items that implement [`Ord`]
+    pub(super) fn is_nested_vec(&self) -> bool {
+        matches!(self, Self::Vec(inner) if inner.is_sortable_vec())
+    }
+
+    /// Parses the type from rust syntax, `None` if any part is not known
+    fn parse(value: &str) -> Option<Self> {
+        if value == LIST {
+            return Some(Self::List);
+        }
+        if value == TREE {
+            return Some(Self::Tree);
+        }
+        if let Some(helper) = HelperType::from_rust_type(value) {
+            return Some(Self::Helper(helper));
+        }
+        if let Some(inner) = value.strip_prefix("Vec<").and_then(|x| x.strip_suffix('>')) {
+            return Some(Self::Vec(Box::new(Self::parse(inner)?)));
+        }
+        if let Some(inner) = value
+            .strip_prefix("Option<")
+            .and_then(|x| x.strip_suffix('>'))
+        {
+            return Some(Self::Option(Box::new(Self::parse(inner)?)));
+        }
+        Primitive::ALL
+            .into_iter()
+            .find(|primitive| primitive.as_str() == value)
+            .map(Self::Primitive)
+    }
+}
+
+impl Display for FunctionArgType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Primitive(primitive) => write!(f, "{}", primitive.as_str()),
+            Self::Vec(inner) => write!(f, "Vec<{inner}>"),
+            Self::Option(inner) => write!(f, "Option<{inner}>"),
+            Self::List => write!(f, "{LIST}"),
+            Self::Tree => write!(f, "{TREE}"),
+            Self::Helper(helper) => write!(f, "{}", helper.rust_type()),
+            Self::Other { raw } => write!(f, "{raw}"),
+        }
+    }
+}
+
+impl From<&str> for FunctionArgType {
+    fn from(value: &str) -> Self {
+        let value = value.trim();
+        let without_whitespace: String = value.chars().filter(|c| !c.is_whitespace()).collect();
+        Self::parse(&without_whitespace).unwrap_or_else(|| {
+            warn!(
+                "Unknown type {value:?} found please report this in an issue https://github.com/rust-practice/cargo-leet/issues/new?&labels=bug&template=missing_type.md"
+            );
+            Self::Other {
+                raw: value.to_string(),
+            }
+        })
+    }
+}
+
+/// Looks up limits in the constraints and records the ones that are missing
+struct Bounds<'a> {
+    constraints: &'a [Constraint],
+    todos: Vec<String>,
+}
+
+impl<'a> Bounds<'a> {
+    /// How far ranges extend from the shortest length or the value closest to
+    /// zero
+    const WINDOW: i64 = 9;
+
+    const fn new(constraints: &'a [Constraint]) -> Self {
+        Self {
+            constraints,
+            todos: vec![],
+        }
+    }
+
+    fn add_todo(&mut self, target: ConstraintTarget) {
+        let target = format!("{target:?}");
+        if !self.todos.contains(&target) {
+            self.todos.push(target);
+        }
+    }
+
+    fn with_todo(self, code: String, message: &str) -> String {
+        if self.todos.is_empty() {
+            code
+        } else {
+            format!("{code} /* TODO: {message}: {} */", self.todos.join(", "))
+        }
+    }
+
+    /// The target for the length of a collection nested in `depth` others,
+    /// only the outer two levels have names in the constraints
+    const fn len_target(depth: usize) -> Option<ConstraintTarget> {
+        match depth {
+            0 => Some(ConstraintTarget::Length),
+            1 => Some(ConstraintTarget::ElementLength),
+            _ => None,
+        }
+    }
+
+    /// Largest value allowed for `target`, limits relative to other arguments
+    /// are used as those are at their limits too in the maximal input
+    ///
+    /// If there is no upper bound the lower one is used (or the smallest
+    /// non-empty length or zero) so the value stays valid, e.g. as an index
+    fn max(&mut self, target: ConstraintTarget) -> i64 {
+        let mut matching = self
+            .constraints
+            .iter()
+            .filter(|constraint| constraint.target == target);
+        if let Some(max) = matching.clone().find_map(|constraint| constraint.max) {
+            return max;
+        }
+        self.add_todo(target);
+        let is_length = matches!(
+            target,
+            ConstraintTarget::Length | ConstraintTarget::ElementLength
+        );
+        matching
+            .find_map(|constraint| constraint.min)
+            .unwrap_or_else(|| i64::from(is_length))
+    }
+
+    /// Characters allowed in strings, `default` if the constraints do not
+    /// list them
+    fn characters(&mut self, default: &str) -> String {
+        let characters = self
+            .constraints
+            .iter()
+            .filter(|constraint| constraint.target == ConstraintTarget::Characters)
+            .find_map(|constraint| constraint.characters.clone());
+        characters.unwrap_or_else(|| {
+            self.add_todo(ConstraintTarget::Characters);
+            default.to_string()
+        })
+    }
+
+    /// Code for a string of `len` times the first allowed character
+    fn repeated_character(&mut self, len: Option<i64>) -> String {
+        let character: String = self.characters("a").chars().take(1).collect();
+        len.map_or_else(
+            || format!("{character:?}.to_string()"),
+            |len| format!("{character:?}.repeat({len})"),
+        )
+    }
+
+    /// Largest length of a collection nested in `depth` others, `None` if the
+    /// constraints cannot describe it
+    fn max_len(&mut self, depth: usize) -> Option<i64> {
+        Self::len_target(depth).map(|target| self.max(target))
+    }
+
+    fn range(&mut self, target: ConstraintTarget, is_unsigned: bool) -> String {
+        let is_length = matches!(
+            target,
+            ConstraintTarget::Length | ConstraintTarget::ElementLength
+        );
+        let is_unsigned = is_unsigned || is_length;
+        let matching = self
+            .constraints
+            .iter()
+            .filter(|constraint| constraint.target == target);
+        let min = matching.clone().find_map(|constraint| constraint.min);
+        // Relative limits do not hold as the other arguments are random too
+        let max = matching
+            .filter(|constraint| !constraint.is_max_relative)
+            .find_map(|constraint| constraint.max);
+        if min.is_none() || max.is_none() {
+            self.add_todo(target);
+        }
+        let min = min.unwrap_or(if is_length {
+            1
+        } else if is_unsigned {
+            0
+        } else {
+            -Self::WINDOW
+        });
+        let default_max = if is_unsigned { min } else { min.max(0) } + Self::WINDOW;
+        let max = max.unwrap_or(default_max).max(min);
+        let (min, max) = if is_unsigned {
+            (min.max(0), max.min(min.max(0) + Self::WINDOW))
+        } else {
+            let pivot = 0.clamp(min, max);
+            (min.max(pivot - Self::WINDOW), max.min(pivot + Self::WINDOW))
+        };
+        format!("{min}..={max}")
+    }
+
+    /// Range of lengths for a collection nested in `depth` others
+    fn len_range(&mut self, depth: usize) -> String {
+        Self::len_target(depth)
+            .map_or_else(|| "1..=3".to_string(), |target| self.range(target, true))
+    }
+}
+
+/// Describes the kind of a json value for error messages
+const fn describe(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a bool",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+fn is_i32(value: i64) -> bool {
+    i32::try_from(value).is_ok()
+}
+
+fn is_u32(value: u64) -> bool {
+    u32::try_from(value).is_ok()
+}
+
+fn clamp_i32(value: i64) -> i64 {
+    value.clamp(i32::MIN.into(), i32::MAX.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn vec(inner: FunctionArgType) -> FunctionArgType {
+        FunctionArgType::Vec(Box::new(inner))
+    }
+
+    #[rstest]
+    #[case("i32", FunctionArgType::Primitive(Primitive::I32))]
+    #[case("String", FunctionArgType::Primitive(Primitive::String_))]
+    #[case(
+        "Vec<Vec<Vec<Vec<char>>>>",
+        vec(vec(vec(vec(FunctionArgType::Primitive(Primitive::Char)))))
+    )]
+    #[case(
+        "Vec< Option<i64> >",
+        vec(FunctionArgType::Option(Box::new(FunctionArgType::Primitive(Primitive::I64))))
+    )]
+    #[case("Option<Box<ListNode>>", FunctionArgType::List)]
+    #[case(
+        "Vec<Vec<Option<Rc<RefCell<TreeNode>>>>>",
+        vec(vec(FunctionArgType::Tree))
+    )]
+    #[case("Vec<UnknownType>", FunctionArgType::Other { raw: "Vec<UnknownType>".into() })]
+    #[case("Vec<i32", FunctionArgType::Other { raw: "Vec<i32".into() })]
+    fn parsing(#[case] input: &str, #[case] expected: FunctionArgType) {
+        let actual = FunctionArgType::from(input);
+        assert_eq!(actual, expected);
+        if !matches!(actual, FunctionArgType::Other { .. }) {
+            assert_eq!(actual.to_string(), input.replace(' ', ""));
+        }
+    }
+
+    #[rstest]
+    #[case("i32", "1", "1")]
+    #[case("i64", "2", "2")]
+    #[case("u32", "11", "11")]
+    #[case("f64", "2.00000", "2.0")]
+    #[case("f64", "-0.5", "-0.5")]
+    #[case("f64", "2", "2.0")]
+    #[case("bool", "true", "true")]
+    #[case("char", r#""b""#, "'b'")]
+    #[case("String", r#""leetcode""#, r#""leetcode""#)]
+    #[case("Vec<i32>", "[1,2,3,4]", "vec![1,2,3,4]")]
+    #[case("Vec<i64>", "[3000000000,-1]", "vec![3000000000,-1]")]
+    #[case(
+        "Vec<f64>",
+        "[6.00000,0.50000,-1.00000,1.00000,-1.00000]",
+        "vec![6.0,0.5,-1.0,1.0,-1.0]"
+    )]
+    #[case(
+        "Vec<bool>",
+        "[true,false,false,false,false]",
+        "vec![true,false,false,false,false]"
+    )]
+    #[case("Vec<char>", r#"["h","e","l","l","o"]"#, "vec!['h','e','l','l','o']")]
+    #[case(
+        "Vec<String>",
+        r#"["@..aA","..B#.","....b"]"#,
+        r#"vec!["@..aA".into(),"..B#.".into(),"....b".into()]"#
+    )]
+    #[case(
+        "Vec<String>",
+        r#"["a,b","[c]"]"#,
+        r#"vec!["a,b".into(),"[c]".into()]"#
+    )]
+    #[case("String", r#""say \"hi\" \\o/""#, r#""say \"hi\" \\o/""#)]
+    #[case(
+        "Vec<String>",
+        r#"["it's","a\"b"]"#,
+        r#"vec!["it's".into(),"a\"b".into()]"#
+    )]
+    #[case("Vec<char>", r#"["'","\\"]"#, r"vec!['\'','\\']")]
+    #[case("Vec<Option<i32>>", "[1,null,2]", "vec![Some(1),None,Some(2)]")]
+    #[case("Vec<Vec<i32>>", "[[2,2,3],[7]]", "vec![vec![2,2,3],vec![7]]")]
+    #[case("Vec<Vec<i32>>", "[[2, 2], []]", "vec![vec![2,2],vec![]]")]
+    #[case(
+        "Vec<Vec<f64>>",
+        "[[1,2.5],[-3,0]]",
+        "vec![vec![1.0,2.5],vec![-3.0,0.0]]"
+    )]
+    #[case(
+        "Vec<Vec<bool>>",
+        "[[true,false],[false,true]]",
+        "vec![vec![true,false],vec![false,true]]"
+    )]
+    #[case(
+        "Vec<Vec<String>>",
+        r#"[["java"],["nodejs"],["nodejs","reactjs"]]"#,
+        r#"vec![vec!["java".into()],vec!["nodejs".into()],vec!["nodejs".into(),"reactjs".into()]]"#
+    )]
+    #[case(
+        "Vec<Vec<char>>",
+        r#"[["X",".",".","X"],[".",".",".","X"],[".",".",".","X"]]"#,
+        "vec![vec!['X','.','.','X'],vec!['.','.','.','X'],vec!['.','.','.','X']]"
+    )]
+    #[case(
+        "Vec<Vec<Vec<i32>>>",
+        "[[[1,2],[3]],[]]",
+        "vec![vec![vec![1,2],vec![3]],vec![]]"
+    )]
+    #[case(
+        "Vec<Vec<Vec<Vec<i32>>>>",
+        "[[[[1]]],[[[]]]]",
+        "vec![vec![vec![vec![1]]],vec![vec![vec![]]]]"
+    )]
+    #[case(
+        "Vec<Vec<Option<i32>>>",
+        "[[null],[1]]",
+        "vec![vec![None],vec![Some(1)]]"
+    )]
+    #[case(
+        "Option<Box<ListNode>>",
+        "[1,2,4]",
+        "ListHead::from(vec![1,2,4]).into()"
+    )]
+    #[case(
+        "Option<Rc<RefCell<TreeNode>>>",
+        "[1, null, 2, 3]",
+        r#"TreeRoot::from("[1,null,2,3]").into()"#
+    )]
+    #[case(
+        "Vec<Option<Box<ListNode>>>",
+        "[[1,4,5],[],[2,6]]",
+        "vec![ListHead::from(vec![1,4,5]).into(),ListHead::from(vec![]).into(),ListHead::from(vec![2,6]).into()]"
+    )]
+    #[case(
+        "Vec<Option<Rc<RefCell<TreeNode>>>>",
+        "[[0,0,0,null,null,0,0],[0,0,0,0,0]]",
+        r#"vec![TreeRoot::from("[0,0,0,null,null,0,0]").into(),TreeRoot::from("[0,0,0,0,0]").into()]"#
+    )]
+    #[case("NestedInteger", "324", r#"NestedInteger::from("324")"#)]
+    #[case(
+        "Vec<NestedInteger>",
+        "[[1,1],2,[1, [1]]]",
+        r#"vec![NestedInteger::from("[1,1]"),NestedInteger::from("2"),NestedInteger::from("[1,[1]]")]"#
+    )]
+    #[case("NestedInteger", r#"[1,"a"]"#, r#"todo!("[1,\"a\"]")"#)]
+    #[case("UnknownType", "1", r#"todo!("1")"#)]
+    #[case("UnknownType", r#"["a\\b","c"]"#, r#"todo!("[\"a\\\\b\",\"c\"]")"#)]
+    #[case("Vec<i32>", "[1,a]", r#"todo!("[1,a]")"#)]
+    #[case("Vec<Vec<i32>>", "[[1],[2]", r#"todo!("[[1],[2]")"#)]
+    fn apply(#[case] arg_type: &str, #[case] input: &str, #[case] expected: &str) {
+        let actual = FunctionArgType::from(arg_type).apply("argument `x`", input, true);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn apply_string_outside_rstest() {
+        let actual = FunctionArgType::Primitive(Primitive::String_).apply(
+            "argument `x`",
+            r#""leetcode""#,
+            false,
+        );
+        assert_eq!(actual, r#""leetcode".into()"#);
+    }
+
+    #[rstest]
+    #[case("Vec<Vec<i32>>", true, true)]
+    #[case("Vec<Vec<Vec<i32>>>", true, true)]
+    #[case("Vec<Option<i32>>", true, false)]
+    #[case("Vec<f64>", false, false)]
+    #[case("Vec<Vec<f64>>", false, false)]
+    #[case("Vec<Option<Box<ListNode>>>", false, false)]
+    #[case("i32", false, false)]
+    fn sortable(#[case] arg_type: &str, #[case] is_sortable: bool, #[case] is_nested: bool) {
+        let arg_type = FunctionArgType::from(arg_type);
+        assert_eq!(arg_type.is_sortable_vec(), is_sortable);
+        assert_eq!(arg_type.is_nested_vec(), is_nested);
+    }
+
+    #[rstest]
+    #[case("Vec<Vec<Vec<Vec<i32>>>>", "vec![vec![vec![vec![0; 1]; 1]; 1]; 1]")]
+    #[case(
+        "Vec<Vec<Option<Box<ListNode>>>>",
+        "(0..1).map(|_| (0..1).map(|_| ListHead::from(vec![0; 1]).into()).collect()).collect()"
+    )]
+    #[case("Vec<Vec<String>>", r#"vec![vec!["a".to_string(); 1]; 1]"#)]
+    fn maximal_value_nested(#[case] arg_type: &str, #[case] expected: &str) {
+        let actual = FunctionArgType::from(arg_type)
+            .get_maximal_value_code(&[])
+            .unwrap();
+        assert_eq!(actual.split(" /*").next().unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("Vec<Vec<i32>>", "g.grid(1..=10, 1..=10, |g| g.i32(-9..=9))")]
+    #[case(
+        "Vec<Vec<Vec<Vec<i32>>>>",
+        "g.vec(1..=10, |g| g.vec(1..=10, |g| g.vec(1..=3, |g| g.vec(1..=3, |g| g.i32(-9..=9)))))"
+    )]
+    #[case(
+        "Vec<Vec<Option<i32>>>",
+        "g.vec(1..=10, |g| g.vec(1..=10, |g| g.bool().then(|| g.i32(-9..=9))))"
+    )]
+    #[case("u32", "g.u32(0..=9)")]
+    fn random_value_nested(#[case] arg_type: &str, #[case] expected: &str) {
+        let actual = FunctionArgType::from(arg_type)
+            .get_random_value_code(&[])
+            .unwrap();
+        assert_eq!(actual.split(" /*").next().unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case(
+        "Vec<Vec<char>>",
+        r#"[["a","b"],["c"],["d",1]]"#,
+        "argument `grid` expected Vec<Vec<char>> but element [2][1] is a number"
+    )]
+    #[case(
+        "Vec<char>",
+        r#"["ab"]"#,
+        "argument `grid` expected Vec<char> but element [0] is a string instead of a single character"
+    )]
+    #[case(
+        "i32",
+        "3000000000",
+        "argument `grid` expected i32 but the value is a number which does not fit in i32"
+    )]
+    #[case(
+        "Option<Box<ListNode>>",
+        "[1,null]",
+        "argument `grid` expected Option<Box<ListNode>> but element [1] is null"
+    )]
+    #[case(
+        "Vec<i32>",
+        "[1,2",
+        "argument `grid` with value \"[1,2\" is not valid json"
+    )]
+    fn convert_errors(#[case] arg_type: &str, #[case] input: &str, #[case] expected: &str) {
+        let actual = FunctionArgType::from(arg_type)
+            .convert("argument `grid`", input, true)
+            .unwrap_err();
+        assert!(
+            actual.starts_with(expected),
+            "Expected {actual:?} to start with {expected:?}"
+        );
+    }
+}
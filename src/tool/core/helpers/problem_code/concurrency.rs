@@ -0,0 +1,332 @@
+//! Tests for concurrency problems (e.g. "Print in Order") where each method of
+//! the struct is called from its own thread and is given callbacks that print
+//!
+//! The callbacks record what they print so it can be compared with the output
+//! of the example
+
+use std::fmt::Write as _;
+
+use log::{info, warn};
+use serde_json::Value;
+
+use super::{arg_type::FunctionArgType, signature};
+
+#[derive(Debug)]
+pub(crate) struct ConcurrencyInfo {
+    struct_name: String,
+    /// Parameters of `new`, given in the examples unless they list the order
+    /// the methods are called in
+    new_params: Vec<signature::Param>,
+    /// Methods to call from the threads in the order they are declared
+    methods: Vec<signature::Signature>,
+}
+
+impl ConcurrencyInfo {
+    pub(super) fn new(code: &str) -> anyhow::Result<Self> {
+        let (struct_name, functions) = signature::parse_impl(code)?;
+        let (constructors, methods): (Vec<_>, Vec<_>) =
+            functions.into_iter().partition(|f| f.name == "new");
+        let new_params = constructors
+            .into_iter()
+            .next()
+            .map(|new| new.params)
+            .unwrap_or_default();
+        let methods: Vec<_> = methods.into_iter().filter(|f| f.has_receiver).collect();
+        if methods.is_empty() {
+            anyhow::bail!("No methods found to call from threads in `impl {struct_name}`");
+        }
+        Ok(Self {
+            struct_name,
+            new_params,
+            methods,
+        })
+    }
+
+    /// Imports needed by [`Self::get_tests`]
+    pub(crate) const fn get_imports() -> &'static str {
+        "use std::sync::{Arc, Mutex};\n"
+    }
+
+    /// A test per example that starts the threads and compares what was
+    /// printed with `solutions`, followed by the function that runs the threads
+    pub(crate) fn get_tests(
+        &self,
+        example_test_case_list: &[String],
+        solutions: &[String],
+    ) -> String {
+        let mut result = String::new();
+        for (i, example) in example_test_case_list.iter().enumerate() {
+            let solution = solutions.get(i).map_or("", String::as_str);
+            let (new_args, calls) = self.get_new_args_and_calls(example);
+            write!(
+                result,
+                "#[test]
+    fn example_{}() {{
+        let output = run({}::new({new_args}), &{calls:?});
+        {}
+    }}
+
+    ",
+                i + 1,
+                self.struct_name,
+                Self::get_output_check(solution)
+            )
+            .expect("write! macro failed");
+        }
+        result.push_str(&self.get_run_fn());
+        result
+    }
+
+    /// Arguments for `new` and the methods to call (1 based index into the
+    /// methods) from the example
+    ///
+    /// If `new` takes no arguments and the example is a list of method numbers
+    /// (e.g. "[1,3,2]" in "Print in Order") it is the order in which the
+    /// threads are started, otherwise the lines are the arguments of `new` and
+    /// each method is started once
+    fn get_new_args_and_calls(&self, example: &str) -> (String, Vec<usize>) {
+        let lines: Vec<_> = example.lines().collect();
+        if self.new_params.is_empty()
+            && let [line] = lines[..]
+            && let Ok(Value::Array(items)) = serde_json::from_str(line)
+        {
+            let calls: Option<Vec<_>> = items
+                .iter()
+                .map(|item| {
+                    item.as_u64()
+                        .and_then(|call| usize::try_from(call).ok())
+                        .filter(|call| (1..=self.methods.len()).contains(call))
+                })
+                .collect();
+            if let Some(calls) = calls {
+                info!("Example is the order the methods are called in: {calls:?}");
+                return (String::new(), calls);
+            }
+        }
+        let calls = (1..=self.methods.len()).collect();
+        if lines.len() != self.new_params.len() {
+            warn!(
+                "Expected {} lines for the arguments of `new` but got {example:?}",
+                self.new_params.len()
+            );
+            return (format!("todo!({example:?})"), calls);
+        }
+        let args: Vec<_> = self
+            .new_params
+            .iter()
+            .zip(lines)
+            .map(|(param, line)| {
+                FunctionArgType::from(param.type_.as_str()).apply(
+                    &format!("argument `{}`", param.identifier),
+                    line,
+                    false,
+                )
+            })
+            .collect();
+        (args.join(", "), calls)
+    }
+
+    /// Compares the printed values with the output of the example, a list is
+    /// compared item by item and anything else as the concatenated text
+    fn get_output_check(solution: &str) -> String {
+        match serde_json::from_str(solution.trim()) {
+            Ok(Value::Array(items)) => {
+                let items: Vec<_> = items
+                    .iter()
+                    .map(|item| match item {
+                        Value::String(s) => format!("{s:?}"),
+                        _ => format!("{:?}", item.to_string()),
+                    })
+                    .collect();
+                format!("assert_eq!(output, [{}]);", items.join(", "))
+            }
+            Ok(Value::String(s)) => format!("assert_eq!(output.concat(), {s:?});"),
+            _ => format!("assert_eq!(output.concat(), {:?});", solution.trim()),
+        }
+    }
+
+    /// Function shared by the tests that calls each method from its own thread
+    /// and collects what is printed by the callbacks
+    fn get_run_fn(&self) -> String {
+        let mut arms = String::new();
+        for (i, method) in self.methods.iter().enumerate() {
+            let args: Vec<_> = method.params.iter().map(Self::get_callback).collect();
+            write!(
+                arms,
+                "
+                    {} => obj.{}({}),",
+                i + 1,
+                method.name,
+                args.join(", ")
+            )
+            .expect("write! macro failed");
+        }
+        format!(
+            "/// Calls each method (1 based index in `calls`) from its own thread
+    /// in the order given and returns what was printed
+    fn run(obj: {struct_name}, calls: &[usize]) -> Vec<String> {{
+        let obj = Arc::new(obj);
+        let output = Arc::new(Mutex::new(vec![]));
+        let handles: Vec<_> = calls
+            .iter()
+            .map(|&call| {{
+                let obj = Arc::clone(&obj);
+                let output = Arc::clone(&output);
+                std::thread::spawn(move || match call {{{arms}
+                    _ => unreachable!(\"no method number {{call}}\"),
+                }})
+            }})
+            .collect();
+        for handle in handles {{
+            handle.join().expect(\"thread panicked\");
+        }}
+        let output = output.lock().unwrap().clone();
+        output
+    }}",
+            struct_name = self.struct_name,
+        )
+    }
+
+    /// Closure that records what it prints for callback parameters (e.g.
+    /// `print_first: impl FnOnce()` pushes "first" and `print_number: impl
+    /// Fn(i32)` pushes the number)
+    fn get_callback(param: &signature::Param) -> String {
+        let Some(args) = signature::callback_args(&param.type_) else {
+            warn!(
+                "Parameter `{}` of type {:?} is not a callback",
+                param.identifier, param.type_
+            );
+            return format!("todo!(\"{}\")", param.identifier);
+        };
+        let record = if args.is_empty() {
+            let printed = param
+                .identifier
+                .strip_prefix("print_")
+                .or_else(|| param.identifier.strip_prefix("print"))
+                .filter(|printed| !printed.is_empty())
+                .unwrap_or(&param.identifier)
+                .to_lowercase();
+            format!("move || output.lock().unwrap().push({printed:?}.to_string())")
+        } else {
+            let names: Vec<_> = (0..args.len()).map(|i| format!("x{i}")).collect();
+            let params: Vec<_> = names
+                .iter()
+                .zip(&args)
+                .map(|(name, type_)| format!("{name}: {type_}"))
+                .collect();
+            let pushes: Vec<_> = names
+                .iter()
+                .map(|name| format!("output.push({name}.to_string());"))
+                .collect();
+            format!(
+                "move |{}| {{ let mut output = output.lock().unwrap(); {} }}",
+                params.join(", "),
+                pushes.join(" ")
+            )
+        };
+        format!("{{ let output = Arc::clone(&output); {record} }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::tool::core::helpers::local_store::tests::insta_settings;
+
+    use super::*;
+
+    const fn get_1114_print_in_order() -> &'static str {
+        "struct Foo {
+
+}
+
+impl Foo {
+    fn new() -> Self {
+        
+    }
+
+    fn first(&self, print_first: impl FnOnce()) {
+        // print_first() outputs \"first\". Do not change or remove this line.
+        print_first();
+    }
+
+    fn second(&self, print_second: impl FnOnce()) {
+        // print_second() outputs \"second\". Do not change or remove this line.
+        print_second();
+    }
+
+    fn third(&self, print_third: impl FnOnce()) {
+        // print_third() outputs \"third\". Do not change or remove this line.
+        print_third();
+    }
+}"
+    }
+
+    const fn get_1116_zero_even_odd() -> &'static str {
+        "struct ZeroEvenOdd {
+    n: i32,
+}
+
+impl ZeroEvenOdd {
+    fn new(n: i32) -> Self {
+        Self { n }
+    }
+
+    // printNumber(x) outputs \"x\", where x is an integer.
+    fn zero(&self, printNumber: impl Fn(i32)) {
+        for i in 0..self.n { if i < self.n { printNumber(0); } }
+    }
+
+    fn even(&self, printNumber: impl Fn(i32)) {
+    }
+
+    fn odd(&self, printNumber: impl Fn(i32)) {
+    }
+}"
+    }
+
+    #[rstest]
+    #[case::print_in_order(
+        "1114",
+        get_1114_print_in_order(),
+        &["[1,2,3]", "[1,3,2]"],
+        &["\"firstsecondthird\"", "\"firstsecondthird\""]
+    )]
+    #[case::zero_even_odd("1116", get_1116_zero_even_odd(), &["2", "5"], &["\"0102\"", "\"0102030405\""])]
+    fn tests_generated(
+        #[case] id: &str,
+        #[case] code: &str,
+        #[case] examples: &[&str],
+        #[case] solutions: &[&str],
+        insta_settings: insta::Settings,
+    ) {
+        let info = ConcurrencyInfo::new(code).unwrap();
+        let examples: Vec<_> = examples.iter().map(ToString::to_string).collect();
+        let solutions: Vec<_> = solutions.iter().map(ToString::to_string).collect();
+        insta_settings.bind(|| {
+            insta::assert_snapshot!(
+                format!("concurrency_tests {id}"),
+                info.get_tests(&examples, &solutions)
+            );
+        });
+    }
+
+    #[rstest]
+    #[case(
+        "\"firstsecondthird\"",
+        r#"assert_eq!(output.concat(), "firstsecondthird");"#
+    )]
+    #[case(r#"[1,2,"fizz"]"#, r#"assert_eq!(output, ["1", "2", "fizz"]);"#)]
+    #[case("HHOHHO", r#"assert_eq!(output.concat(), "HHOHHO");"#)]
+    fn output_check(#[case] solution: &str, #[case] expected: &str) {
+        assert_eq!(ConcurrencyInfo::get_output_check(solution), expected);
+    }
+
+    #[test]
+    fn no_methods() {
+        assert!(
+            ConcurrencyInfo::new("struct Foo;\nimpl Foo {\n    fn new() -> Self {}\n}").is_err()
+        );
+    }
+}
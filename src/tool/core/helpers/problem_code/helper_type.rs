@@ -0,0 +1,123 @@
+//! Types that leetcode defines for some problems (e.g. `NestedInteger`) which
+//! `cargo_leet` provides so the snippet compiles locally
+
+use serde_json::Value;
+
+/// Registry of the helper types known to `cargo_leet`
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub(super) enum HelperType {
+    NestedInteger,
+    MountainArray,
+    /// Node of an N-ary tree, used as `Option<Rc<RefCell<Node>>>`
+    Node,
+    Employee,
+    ImmutableListNode,
+}
+
+impl HelperType {
+    // Search Key: SK_ADD_HELPER_TYPE
+    // Add the new type here and in `cargo_leet` (leetcode_env) with a way to
+    // build it from the format leetcode uses in the examples
+    const ALL: [Self; 5] = [
+        Self::NestedInteger,
+        Self::MountainArray,
+        Self::Node,
+        Self::Employee,
+        Self::ImmutableListNode,
+    ];
+
+    /// Name of the type in the snippet and in `cargo_leet`
+    pub(super) const fn name(self) -> &'static str {
+        match self {
+            Self::NestedInteger => "NestedInteger",
+            Self::MountainArray => "MountainArray",
+            Self::Node => "Node",
+            Self::Employee => "Employee",
+            Self::ImmutableListNode => "ImmutableListNode",
+        }
+    }
+
+    /// Type of the arguments using the helper type (without `&`)
+    pub(super) const fn rust_type(self) -> &'static str {
+        match self {
+            Self::Node => "Option<Rc<RefCell<Node>>>",
+            _ => self.name(),
+        }
+    }
+
+    pub(super) fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|helper| helper.name() == name)
+    }
+
+    /// Matches a type without whitespace (e.g. `Option<Rc<RefCell<Node>>>`)
+    pub(super) fn from_rust_type(type_: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|helper| helper.rust_type() == type_)
+    }
+
+    /// Type of the values the solution prints through the helper type, these
+    /// are checked instead of the return value (e.g.
+    /// `ImmutableListNode::printValue`)
+    pub(super) const fn printed_type(self) -> Option<&'static str> {
+        match self {
+            Self::ImmutableListNode => Some("Vec<i32>"),
+            _ => None,
+        }
+    }
+
+    /// Converts the value of an example into a rust expression of the type,
+    /// `None` if the value does not match the format of the type
+    pub(super) fn to_rust(self, value: &Value) -> Option<String> {
+        match self {
+            Self::NestedInteger => {
+                is_nested_integer(value).then(|| format!(r#"NestedInteger::from("{value}")"#))
+            }
+            Self::MountainArray => match value {
+                Value::Array(items) if items.iter().all(is_integer) => {
+                    Some(format!("MountainArray::new(vec!{value})"))
+                }
+                _ => None,
+            },
+            Self::Node => match value {
+                Value::Array(items) if items.iter().all(|x| x.is_null() || is_integer(x)) => Some(
+                    format!(r#"cargo_leet::NaryTreeRoot::from("{value}").into()"#),
+                ),
+                _ => None,
+            },
+            Self::Employee => match value {
+                Value::Array(items) => match &items[..] {
+                    [id, importance, Value::Array(subordinates)]
+                        if is_integer(id)
+                            && is_integer(importance)
+                            && subordinates.iter().all(is_integer) =>
+                    {
+                        Some(format!(
+                            "Employee::new({id}, {importance}, vec!{})",
+                            items[2]
+                        ))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            },
+            Self::ImmutableListNode => match value {
+                Value::Array(items) if !items.is_empty() && items.iter().all(is_integer) => {
+                    Some(format!("ImmutableListNode::from(vec!{value})"))
+                }
+                _ => None,
+            },
+        }
+    }
+}
+
+fn is_nested_integer(value: &Value) -> bool {
+    match value {
+        Value::Array(items) => items.iter().all(is_nested_integer),
+        _ => is_integer(value),
+    }
+}
+
+fn is_integer(value: &Value) -> bool {
+    value.as_i64().is_some_and(|n| i32::try_from(n).is_ok())
+}
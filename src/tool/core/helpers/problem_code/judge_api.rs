@@ -0,0 +1,50 @@
+//! APIs the judge provides for interactive problems where the value to find is
+//! hidden (e.g. `guess` in "Guess Number Higher or Lower")
+//!
+//! The examples list the hidden value after the arguments of the function so
+//! the generated tests take it as an extra value and pass it to
+//! `cargo_leet::set_secret` before calling the solution
+
+/// Registry of the judge APIs mocked by `cargo_leet` that are backed by a
+/// secret
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub(super) enum JudgeApi {
+    Guess,
+    BadVersion,
+}
+
+impl JudgeApi {
+    const ALL: [Self; 2] = [Self::Guess, Self::BadVersion];
+
+    /// Finds the API in the code leetcode provides, the APIs are only
+    /// declared in comments so the text is searched
+    pub(super) fn detect(code: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|api| code.contains(api.declaration()))
+    }
+
+    /// Part of the comment leetcode uses to declare the API
+    const fn declaration(self) -> &'static str {
+        match self {
+            Self::Guess => "fn guess(",
+            Self::BadVersion => "isBadVersion(",
+        }
+    }
+
+    /// Item imported from `cargo_leet` that makes the API available
+    pub(super) const fn import_name(self) -> &'static str {
+        match self {
+            Self::Guess => "guess",
+            Self::BadVersion => "VersionControl",
+        }
+    }
+
+    /// Name of the secret in the description of the examples
+    pub(super) const fn secret_name(self) -> &'static str {
+        match self {
+            Self::Guess => "pick",
+            Self::BadVersion => "bad",
+        }
+    }
+}
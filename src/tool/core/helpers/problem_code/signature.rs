@@ -0,0 +1,561 @@
+//! Extracts the signature of the solution function from the code leetcode
+//! provides
+//!
+//! A small tokenizer is used instead of a regex so the layout of the code
+//! (parameters over multiple lines, trailing commas, comments, generics) does
+//! not matter
+
+use anyhow::{Context as _, bail};
+
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct Signature {
+    pub(super) name: String,
+    pub(super) params: Vec<Param>,
+    pub(super) return_type: Option<String>,
+    /// Declared as `unsafe fn` (e.g. when calling an unsafe judge API)
+    pub(super) is_unsafe: bool,
+    /// Takes `self` so it is called as a method (e.g. to use a judge API on
+    /// `self`)
+    pub(super) has_receiver: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct Param {
+    pub(super) identifier: String,
+    /// Type without the leading `&mut` or `&`
+    pub(super) type_: String,
+    pub(super) is_mut_ref: bool,
+    pub(super) is_ref: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    Ident,
+    Lifetime,
+    Literal,
+    Punct,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+}
+
+impl Token<'_> {
+    fn is(&self, text: &str) -> bool {
+        self.text == text && self.kind != TokenKind::Literal
+    }
+
+    const fn is_word(&self) -> bool {
+        matches!(
+            self.kind,
+            TokenKind::Ident | TokenKind::Lifetime | TokenKind::Literal
+        )
+    }
+}
+
+/// Parses the first function in `impl Solution`
+pub(super) fn parse(code: &str) -> anyhow::Result<Signature> {
+    let tokens = tokenize(code).context("Failed to tokenize code")?;
+    let impl_start = tokens
+        .windows(3)
+        .position(|w| w[0].is("impl") && w[1].is("Solution") && w[2].is("{"))
+        .context("`impl Solution` not found")?;
+    let rest = &tokens[impl_start + 3..];
+    let fn_start = rest
+        .iter()
+        .position(|token| token.is("fn"))
+        .context("No function found in `impl Solution`")?;
+    let is_unsafe = fn_start > 0 && rest[fn_start - 1].is("unsafe");
+    let (signature, _) = parse_fn(&rest[fn_start + 1..], is_unsafe)?;
+    Ok(signature)
+}
+
+/// Parses all the functions in the first `impl` block, returns the name of
+/// the type and the functions (e.g. the struct of a concurrency problem)
+pub(super) fn parse_impl(code: &str) -> anyhow::Result<(String, Vec<Signature>)> {
+    let tokens = tokenize(code).context("Failed to tokenize code")?;
+    let impl_start = tokens
+        .windows(3)
+        .position(|w| w[0].is("impl") && w[1].kind == TokenKind::Ident && w[2].is("{"))
+        .context("No `impl` block found")?;
+    let name = tokens[impl_start + 1].text.to_string();
+    let body = &tokens[impl_start + 2..];
+    let body_end = find_closing_brace(body).context("`impl` block not closed")?;
+    let mut rest = &body[1..body_end];
+    let mut functions = vec![];
+    while let Some(fn_start) = rest.iter().position(|token| token.is("fn")) {
+        let is_unsafe = fn_start > 0 && rest[fn_start - 1].is("unsafe");
+        let (signature, len) = parse_fn(&rest[fn_start + 1..], is_unsafe)
+            .with_context(|| format!("Failed to parse function {}", functions.len() + 1))?;
+        rest = &rest[fn_start + 1 + len..];
+        if rest.first().is_some_and(|token| token.is("{")) {
+            let end = find_closing_brace(rest).context("Function body not closed")?;
+            rest = &rest[end + 1..];
+        }
+        functions.push(signature);
+    }
+    Ok((name, functions))
+}
+
+/// Parses the signature of a function from `tokens` starting after `fn`,
+/// also returns the number of tokens used (up to the body)
+fn parse_fn(tokens: &[Token], is_unsafe: bool) -> anyhow::Result<(Signature, usize)> {
+    let name = match tokens.first() {
+        Some(token) if token.kind == TokenKind::Ident => token.text.to_string(),
+        _ => bail!("Function name not found in code"),
+    };
+    let mut rest = &tokens[1..];
+
+    // Skip generic parameters
+    if rest.first().is_some_and(|token| token.is("<")) {
+        let end = find_closing(rest).context("Generic parameters not closed")?;
+        rest = &rest[end + 1..];
+    }
+
+    if !rest.first().is_some_and(|token| token.is("(")) {
+        bail!("Function arguments not found");
+    }
+    let end = find_closing(rest).context("Function arguments not closed")?;
+    let (receivers, params): (Vec<_>, Vec<_>) = split_top_level(&rest[1..end], ",")
+        .into_iter()
+        .filter(|param| !param.is_empty())
+        .partition(|param| is_receiver(param));
+    let params = params
+        .into_iter()
+        .map(parse_param)
+        .collect::<anyhow::Result<_>>()?;
+    rest = &rest[end + 1..];
+
+    let before_body = split_top_level(rest, "{")
+        .into_iter()
+        .next()
+        .unwrap_or_default();
+    let return_type = if before_body.first().is_some_and(|token| token.is("->")) {
+        let type_ = split_top_level(&before_body[1..], "where")
+            .into_iter()
+            .next()
+            .unwrap_or_default();
+        if type_.is_empty() {
+            bail!("Return type not found after `->`");
+        }
+        Some(render(type_))
+    } else {
+        None
+    };
+    let len = tokens.len() - rest.len() + before_body.len();
+
+    Ok((
+        Signature {
+            name,
+            params,
+            return_type,
+            is_unsafe,
+            has_receiver: !receivers.is_empty(),
+        },
+        len,
+    ))
+}
+
+/// Identifiers used in `code` outside of comments and literals
+pub(super) fn identifiers(code: &str) -> anyhow::Result<Vec<&str>> {
+    Ok(tokenize(code)?
+        .into_iter()
+        .filter(|token| token.kind == TokenKind::Ident)
+        .map(|token| token.text)
+        .collect())
+}
+
+/// Names of the types defined in `code` outside of comments (e.g. `Node` for
+/// `struct Node`)
+pub(super) fn defined_types(code: &str) -> anyhow::Result<Vec<&str>> {
+    Ok(tokenize(code)?
+        .windows(2)
+        .filter(|w| {
+            ["struct", "enum", "trait", "type"]
+                .iter()
+                .any(|keyword| w[0].is(keyword))
+                && w[1].kind == TokenKind::Ident
+        })
+        .map(|w| w[1].text)
+        .collect())
+}
+
+/// Types of the arguments of a callback type (e.g. `impl Fn(i32)`), `None` if
+/// `type_` is not a callback
+pub(super) fn callback_args(type_: &str) -> Option<Vec<String>> {
+    let tokens = tokenize(type_).ok()?;
+    let start = tokens
+        .windows(2)
+        .position(|w| (w[0].is("Fn") || w[0].is("FnMut") || w[0].is("FnOnce")) && w[1].is("("))?
+        + 1;
+    let end = find_closing(&tokens[start..])?;
+    Some(
+        split_top_level(&tokens[start + 1..start + end], ",")
+            .into_iter()
+            .filter(|arg| !arg.is_empty())
+            .map(render)
+            .collect(),
+    )
+}
+
+/// `self`, `&self`, `&mut self`, `mut self` or `self: Type`
+fn is_receiver(param: &[Token]) -> bool {
+    let before_colon = split_top_level(param, ":")
+        .into_iter()
+        .next()
+        .unwrap_or_default();
+    before_colon.last().is_some_and(|token| token.is("self"))
+}
+
+fn parse_param(param: &[Token]) -> anyhow::Result<Param> {
+    let rendered = render(param);
+    let (identifier, type_) = match param {
+        [mut_, identifier, colon, type_ @ ..] if mut_.is("mut") && colon.is(":") => {
+            (identifier, type_)
+        }
+        [identifier, colon, type_ @ ..] if colon.is(":") => (identifier, type_),
+        _ => bail!("Expected `name: Type` but found {rendered:?}"),
+    };
+    if identifier.kind != TokenKind::Ident || type_.is_empty() {
+        bail!("Expected `name: Type` but found {rendered:?}");
+    }
+    let (type_, is_mut_ref, is_ref) = match type_ {
+        [and, mut_, type_ @ ..] if and.is("&") && mut_.is("mut") && !type_.is_empty() => {
+            (type_, true, false)
+        }
+        [and, type_ @ ..] if and.is("&") && !type_.is_empty() => (type_, false, true),
+        _ => (type_, false, false),
+    };
+    Ok(Param {
+        identifier: identifier.text.to_string(),
+        type_: render(type_),
+        is_mut_ref,
+        is_ref,
+    })
+}
+
+/// Index of the token closing the bracket that `tokens` starts with
+fn find_closing(tokens: &[Token]) -> Option<usize> {
+    let mut expected_closers = vec![];
+    for (i, token) in tokens.iter().enumerate() {
+        if token.kind != TokenKind::Punct {
+            continue;
+        }
+        match token.text {
+            "(" => expected_closers.push(")"),
+            "[" => expected_closers.push("]"),
+            "<" => expected_closers.push(">"),
+            "{" => expected_closers.push("}"),
+            ")" | "]" | ">" | "}" => {
+                if expected_closers.pop()? != token.text {
+                    return None;
+                }
+                if expected_closers.is_empty() {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Index of the `}` closing the `{` that `tokens` starts with, other brackets
+/// are ignored as `<` is also used for comparisons in function bodies
+fn find_closing_brace(tokens: &[Token]) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, token) in tokens.iter().enumerate() {
+        if token.is("{") {
+            depth += 1;
+        } else if token.is("}") {
+            depth = depth.checked_sub(1)?;
+            if depth == 0 {
+                return Some(i);
+            }
+        }
+    }
+    None
+}
+
+/// Splits `tokens` at each `separator` that is not nested in brackets
+fn split_top_level<'a, 'b>(tokens: &'b [Token<'a>], separator: &str) -> Vec<&'b [Token<'a>]> {
+    let mut result = vec![];
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        if depth == 0 && token.is(separator) {
+            result.push(&tokens[start..i]);
+            start = i + 1;
+            continue;
+        }
+        if token.kind == TokenKind::Punct {
+            match token.text {
+                "(" | "[" | "<" | "{" => depth += 1,
+                ")" | "]" | ">" | "}" => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+    }
+    result.push(&tokens[start..]);
+    result
+}
+
+/// Joins tokens back into code with spaces only where they are needed (e.g.
+/// `&mut Vec<i32>` or `impl Iterator<Item=i32>`)
+fn render(tokens: &[Token]) -> String {
+    let mut result = String::new();
+    for (i, token) in tokens.iter().enumerate() {
+        if i > 0 && tokens[i - 1].is_word() && token.is_word() {
+            result.push(' ');
+        }
+        result.push_str(token.text);
+    }
+    result
+}
+
+fn tokenize(code: &str) -> anyhow::Result<Vec<Token<'_>>> {
+    let is_ident_char = |c: char| c.is_alphanumeric() || c == '_';
+    let ident_len = |s: &str| s.find(|c| !is_ident_char(c)).unwrap_or(s.len());
+    let mut result = vec![];
+    let mut start = 0;
+    while let Some(c) = code[start..].chars().next() {
+        let rest = &code[start..];
+        let (kind, len) = if c.is_whitespace() {
+            start += c.len_utf8();
+            continue;
+        } else if rest.starts_with("//") {
+            start += rest.find('\n').unwrap_or(rest.len());
+            continue;
+        } else if rest.starts_with("/*") {
+            start += rest.find("*/").context("Unterminated block comment")? + 2;
+            continue;
+        } else if c.is_ascii_digit() {
+            (TokenKind::Literal, ident_len(rest))
+        } else if is_ident_char(c) {
+            (TokenKind::Ident, ident_len(rest))
+        } else if c == '"' || c == '\'' {
+            let lifetime_len = 1 + ident_len(&rest[1..]);
+            if c == '\'' && lifetime_len > 1 && !rest[lifetime_len..].starts_with('\'') {
+                (TokenKind::Lifetime, lifetime_len)
+            } else {
+                (
+                    TokenKind::Literal,
+                    quoted_len(rest).context("Unterminated literal")?,
+                )
+            }
+        } else if rest.starts_with("->") || rest.starts_with("::") {
+            (TokenKind::Punct, 2)
+        } else {
+            (TokenKind::Punct, c.len_utf8())
+        };
+        result.push(Token {
+            kind,
+            text: &rest[..len],
+        });
+        start += len;
+    }
+    Ok(result)
+}
+
+/// Length of the string or char literal `s` starts with including the quotes
+fn quoted_len(s: &str) -> Option<usize> {
+    let quote = s.chars().next()?;
+    let mut is_escaped = false;
+    for (i, c) in s.char_indices().skip(1) {
+        match c {
+            _ if is_escaped => is_escaped = false,
+            '\\' => is_escaped = true,
+            _ if c == quote => return Some(i + c.len_utf8()),
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// `type_` as in the signature, the reference is moved to the flags
+    fn param(identifier: &str, type_: &str) -> Param {
+        let (type_, is_mut_ref, is_ref) =
+            match (type_.strip_prefix("&mut "), type_.strip_prefix('&')) {
+                (Some(type_), _) => (type_, true, false),
+                (None, Some(type_)) => (type_, false, true),
+                (None, None) => (type_, false, false),
+            };
+        Param {
+            identifier: identifier.into(),
+            type_: type_.into(),
+            is_mut_ref,
+            is_ref,
+        }
+    }
+
+    #[rstest]
+    #[case::single_line(
+        "impl Solution {\n    pub fn two_sum(nums: Vec<i32>, target: i32) -> Vec<i32> {\n    }\n}",
+        "two_sum",
+        vec![param("nums", "Vec<i32>"), param("target", "i32")],
+        Some("Vec<i32>")
+    )]
+    #[case::multi_line_trailing_comma(
+        "impl Solution {
+    pub fn max_profit(
+        prices: Vec<i32>,
+        fee: i32,
+    ) -> i32 {
+        todo!()
+    }
+}",
+        "max_profit",
+        vec![param("prices", "Vec<i32>"), param("fee", "i32")],
+        Some("i32")
+    )]
+    #[case::mut_ref_no_return(
+        "impl Solution {\n    pub fn rotate(nums: &mut Vec<i32>, k: i32) {\n        \n    }\n}",
+        "rotate",
+        vec![param("nums", "&mut Vec<i32>"), param("k", "i32")],
+        None
+    )]
+    #[case::receiver_generics_and_lifetimes(
+        "impl Solution {
+    pub fn f<'a, T: Ord>(&self, s: &'a str, items: HashMap<i32, Vec<T>>) -> impl Iterator<Item = T> + 'a {
+    }
+}",
+        "f",
+        vec![param("s", "&'a str"), param("items", "HashMap<i32,Vec<T>>")],
+        Some("impl Iterator<Item=T>+'a")
+    )]
+    #[case::comments_and_where(
+        "// Definition for a binary tree node.
+// pub fn not_this(x: i32) {}
+impl Solution {
+    /* pub fn nor_this() */
+    pub fn max_depth(root: Option<Rc<RefCell<TreeNode>>> /* the root */) -> i32 where Self: Sized {
+    }
+}",
+        "max_depth",
+        vec![param("root", "Option<Rc<RefCell<TreeNode>>>")],
+        Some("i32")
+    )]
+    #[case::no_args("impl Solution {\n    pub fn f() -> bool {\n    }\n}", "f", vec![], Some("bool"))]
+    fn parsing(
+        #[case] code: &str,
+        #[case] name: &str,
+        #[case] params: Vec<Param>,
+        #[case] return_type: Option<&str>,
+    ) {
+        let signature = parse(code).unwrap();
+        assert_eq!(signature.name, name);
+        assert_eq!(signature.params, params);
+        assert_eq!(signature.return_type.as_deref(), return_type);
+    }
+
+    #[rstest]
+    #[case::plain("impl Solution {\n    pub fn f(n: i32) {}\n}", false, false)]
+    #[case::guess_number(
+        "/**
+ * unsafe fn guess(num: i32) -> i32 {}
+ */
+impl Solution {
+    unsafe fn guessNumber(n: i32) -> i32 {
+    }
+}",
+        true,
+        false
+    )]
+    #[case::first_bad_version(
+        "// The API isBadVersion is defined for you.
+// isBadVersion(version:i32)-> bool;
+// to call it use self.isBadVersion(version)
+
+impl Solution {
+    pub fn first_bad_version(&self, n: i32) -> i32 {
+    }
+}",
+        false,
+        true
+    )]
+    fn function_kind(#[case] code: &str, #[case] is_unsafe: bool, #[case] has_receiver: bool) {
+        let signature = parse(code).unwrap();
+        assert_eq!(signature.is_unsafe, is_unsafe);
+        assert_eq!(signature.has_receiver, has_receiver);
+        assert_eq!(signature.params, vec![param("n", "i32")]);
+    }
+
+    #[rstest]
+    #[case("struct Foo {}")]
+    #[case("impl Solution {\n}")]
+    #[case("impl Solution {\n    pub fn f(x) {}\n}")]
+    #[case("impl Solution {\n    pub fn f(x: i32 {}\n}")]
+    fn parsing_fails(#[case] code: &str) {
+        assert!(parse(code).is_err());
+    }
+
+    #[test]
+    fn parsing_impl() {
+        let code = "struct Foo {}
+impl Foo {
+    pub fn new(n: i32) -> Self {
+        if n < 3 { Self {} } else { Self {} }
+    }
+
+    fn print(&self, print_number: impl Fn(i32) + Send, done: Box<dyn FnOnce()>) {}
+}
+impl Bar {
+    fn not_this() {}
+}";
+        let (name, functions) = parse_impl(code).unwrap();
+        assert_eq!(name, "Foo");
+        let names: Vec<_> = functions.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["new", "print"]);
+        assert_eq!(functions[0].return_type.as_deref(), Some("Self"));
+        assert!(functions[1].has_receiver);
+        assert_eq!(
+            callback_args(&functions[1].params[0].type_),
+            Some(vec!["i32".to_string()])
+        );
+        assert_eq!(callback_args(&functions[1].params[1].type_), Some(vec![]));
+        assert_eq!(callback_args("Vec<i32>"), None);
+    }
+
+    #[test]
+    fn tokenizing() {
+        let tokens = tokenize(r#"fn f<'a>(c: char) -> &'a str { let x = '\''; "a\"b" }"#).unwrap();
+        let texts: Vec<_> = tokens.iter().map(|token| token.text).collect();
+        assert_eq!(
+            texts,
+            [
+                "fn",
+                "f",
+                "<",
+                "'a",
+                ">",
+                "(",
+                "c",
+                ":",
+                "char",
+                ")",
+                "->",
+                "&",
+                "'a",
+                "str",
+                "{",
+                "let",
+                "x",
+                "=",
+                r"'\''",
+                ";",
+                r#""a\"b""#,
+                "}"
+            ]
+        );
+        assert_eq!(tokens[3].kind, TokenKind::Lifetime);
+        assert_eq!(tokens[18].kind, TokenKind::Literal);
+    }
+}
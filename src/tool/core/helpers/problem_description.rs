@@ -4,7 +4,10 @@ use log::info;
 
 use super::{get_response, local_store::path_local_store_problem_description};
 
+pub(crate) mod constraints;
 pub(crate) mod data_structure;
+mod examples;
+mod markdown;
 
 pub(crate) fn get_problem_description(
     title_slug: &str,
@@ -100,6 +103,48 @@ mod tests {
         }
     }
 
+    #[rstest]
+    fn description_as_markdown(title_slugs: SlugList, insta_settings: insta::Settings) {
+        for title_slug in title_slugs {
+            insta_settings.bind(|| {
+                let problem_description = get_problem_description(title_slug).unwrap();
+                insta::assert_snapshot!(
+                    format!("markdown {title_slug}"),
+                    problem_description.to_markdown()
+                );
+            });
+        }
+    }
+
+    #[rstest]
+    fn extract_examples_from_description(title_slugs: SlugList, insta_settings: insta::Settings) {
+        for title_slug in title_slugs {
+            insta_settings.bind(|| {
+                let problem_description = get_problem_description(title_slug).unwrap();
+                insta::assert_debug_snapshot!(
+                    format!("examples {title_slug}"),
+                    problem_description.get_examples()
+                );
+            });
+        }
+    }
+
+    #[rstest]
+    fn extract_constraints_from_description(
+        title_slugs: SlugList,
+        insta_settings: insta::Settings,
+    ) {
+        for title_slug in title_slugs {
+            insta_settings.bind(|| {
+                let problem_description = get_problem_description(title_slug).unwrap();
+                insta::assert_debug_snapshot!(
+                    format!("constraints {title_slug}"),
+                    problem_description.get_constraints()
+                );
+            });
+        }
+    }
+
     #[rstest]
     fn extract_solutions_from_description(title_slugs: SlugList, insta_settings: insta::Settings) {
         for title_slug in title_slugs {
@@ -0,0 +1,523 @@
+//! Extraction of the constraints listed at the end of a problem description
+//!
+//! Supports constraints of the forms:
+//! - `2 <= nums.length <= 10^4`
+//! - `-10^9 <= nums[i], target <= 10^9`
+//! - `0 <= image[i][j] < 2^16`
+//! - `The number of nodes in the list is in the range [1, 100]`
+//! - `-100 <= Node.val <= 100`
+//! - `s consists of lowercase English letters`
+//!
+//! Names given to lengths (e.g. `m == grid.length`) are replaced with what
+//! they stand for and limits may refer to other arguments (e.g. `0 <= sr < m`).
+//! Anything else is ignored
+
+use convert_case::{Case, Casing};
+use log::debug;
+use regex::Regex;
+
+/// What part of an argument a [`Constraint`] limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ConstraintTarget {
+    /// The value itself (e.g. `target`)
+    Value,
+    /// The number of items (e.g. `nums.length` or the number of nodes)
+    Length,
+    /// Each item (e.g. `nums[i]` or `Node.val`)
+    Element,
+    /// The number of items in each item (e.g. `grid[i].length`)
+    ElementLength,
+    /// The characters of a string or of each string in it
+    Characters,
+}
+
+/// Inclusive bounds on an argument of the function
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Constraint {
+    /// Name of the argument in snake case, `None` if the constraint is on the
+    /// nodes of a list or tree
+    pub(crate) identifier: Option<String>,
+    pub(crate) target: ConstraintTarget,
+    pub(crate) min: Option<i64>,
+    pub(crate) max: Option<i64>,
+    /// `max` was derived from the limit of another argument (e.g. `sr < m`)
+    /// so it only holds if that argument is at its limit too
+    pub(crate) is_max_relative: bool,
+    /// Allowed characters for [`ConstraintTarget::Characters`]
+    pub(crate) characters: Option<String>,
+}
+
+impl Constraint {
+    /// Returns true if the constraint applies to the argument `identifier`
+    pub(crate) fn is_for(&self, identifier: &str, is_list_or_tree: bool) -> bool {
+        self.identifier
+            .as_deref()
+            .map_or(is_list_or_tree, |x| x == identifier)
+    }
+}
+
+/// Parses the items of the `Constraints:` list in the markdown of a description
+pub(crate) fn parse_constraints(markdown: &str) -> Vec<Constraint> {
+    let lines: Vec<_> = markdown
+        .lines()
+        .skip_while(|line| !line.contains("Constraints:"))
+        .skip(1)
+        .skip_while(|line| line.trim().is_empty())
+        .take_while(|line| line.starts_with("- "))
+        .map(|line| clean_line(line.trim_start_matches("- ")))
+        .collect();
+    let lines = replace_aliases(&lines);
+
+    // Limits that refer to other arguments need theirs to be known first
+    let absolute: Vec<_> = lines
+        .iter()
+        .flat_map(|line| parse_constraint_line(line, &|_| None))
+        .collect();
+    let limit_of = |variable: &str| {
+        let (identifier, target) = parse_subject(variable)?;
+        absolute
+            .iter()
+            .filter(|constraint| constraint.identifier == identifier && constraint.target == target)
+            .find_map(|constraint| constraint.max)
+    };
+    lines
+        .iter()
+        .flat_map(|line| {
+            let result = parse_constraint_line(line, &limit_of);
+            if result.is_empty() {
+                debug!("No constraint found in {line:?}");
+            }
+            result
+        })
+        .collect()
+}
+
+/// Replaces names given to lengths (e.g. `m` in `m == grid.length`) in the
+/// other lines with what they stand for
+fn replace_aliases(lines: &[String]) -> Vec<String> {
+    let alias_re =
+        Regex::new(r"^([A-Za-z_][A-Za-z_0-9]*)\s*==\s*(\S+)$").expect("compiling static regex");
+    let aliases: Vec<_> = lines
+        .iter()
+        .filter_map(|line| {
+            let caps = alias_re.captures(line)?;
+            let (_, target) = parse_subject(&caps[2])?;
+            (target != ConstraintTarget::Value).then(|| {
+                let name_re =
+                    Regex::new(&format!(r"\b{}\b", &caps[1])).expect("name is a valid identifier");
+                (name_re, caps[2].to_string())
+            })
+        })
+        .collect();
+    lines
+        .iter()
+        .map(|line| {
+            if alias_re.is_match(line) {
+                return line.clone();
+            }
+            aliases
+                .iter()
+                .fold(line.clone(), |line, (name_re, subject)| {
+                    name_re.replace_all(&line, subject.as_str()).into_owned()
+                })
+        })
+        .collect()
+}
+
+/// Removes the markdown formatting from a line
+fn clean_line(line: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '`' | '*' => {}
+            '\\' => result.extend(chars.next()),
+            _ => result.push(c),
+        }
+    }
+    result.trim().trim_end_matches('.').to_string()
+}
+
+/// `limit_of` returns the largest value of a variable used in a limit (e.g.
+/// `m` in `0 <= sr < m`)
+fn parse_constraint_line(line: &str, limit_of: &dyn Fn(&str) -> Option<i64>) -> Vec<Constraint> {
+    let range_re = Regex::new(r"(?i)number of nodes in .* is in the range \[(.+),(.+)\]")
+        .expect("compiling static regex");
+    if let Some(caps) = range_re.captures(line) {
+        return vec![Constraint {
+            identifier: None,
+            target: ConstraintTarget::Length,
+            min: evaluate(&caps[1]),
+            max: evaluate(&caps[2]),
+            is_max_relative: false,
+            characters: None,
+        }];
+    }
+
+    if let Some(result) = parse_characters_line(line) {
+        return result;
+    }
+
+    let relation_re = Regex::new(r"^(?:(.+?)\s*(<=|<)\s*)?([^<>=]+?)\s*(<=|<)\s*(.+)$")
+        .expect("compiling static regex");
+    let Some(caps) = relation_re.captures(line) else {
+        return vec![];
+    };
+    let mut min = caps.get(1).and_then(|x| evaluate(x.as_str()));
+    let absolute_max = evaluate(&caps[5]);
+    let relative_max = absolute_max
+        .is_none()
+        .then(|| evaluate_with_variables(&caps[5], limit_of))
+        .flatten();
+    let is_max_relative = relative_max.is_some();
+    let mut max = absolute_max.or(relative_max);
+    if caps.get(2).is_some_and(|op| op.as_str() == "<") {
+        min = min.and_then(|x| x.checked_add(1));
+    }
+    if &caps[4] == "<" {
+        max = max.and_then(|x| x.checked_sub(1));
+    }
+    if min.is_none() && max.is_none() {
+        return vec![];
+    }
+
+    caps[3]
+        .split(',')
+        .filter_map(|subject| {
+            let (identifier, target) = parse_subject(subject.trim())?;
+            Some(Constraint {
+                identifier,
+                target,
+                min,
+                max,
+                is_max_relative,
+                characters: None,
+            })
+        })
+        .collect()
+}
+
+/// Parses lines like `s and t consist of lowercase English letters`, `None`
+/// if the line is not about the characters of strings
+fn parse_characters_line(line: &str) -> Option<Vec<Constraint>> {
+    let re = Regex::new(r"^(.+?) consists? (?:only )?of (.+)$").expect("compiling static regex");
+    let caps = re.captures(line)?;
+    let characters = parse_characters(&caps[2]);
+    if characters.is_none() {
+        debug!("Characters not recognized in {line:?}");
+    }
+    let subject_re = Regex::new(r",\s*|\s+and\s+").expect("compiling static regex");
+    Some(
+        subject_re
+            .split(&caps[1])
+            .filter_map(|subject| {
+                let (identifier, _) = parse_subject(subject.trim())?;
+                Some(Constraint {
+                    identifier,
+                    target: ConstraintTarget::Characters,
+                    min: None,
+                    max: None,
+                    is_max_relative: false,
+                    characters: characters.clone(),
+                })
+            })
+            .collect(),
+    )
+}
+
+/// Characters listed in quotes (e.g. `'()[]{}'`) or named (e.g. `lowercase
+/// English letters` or `digits`)
+fn parse_characters(description: &str) -> Option<String> {
+    let quoted_re = Regex::new(r"'([^']+)'").expect("compiling static regex");
+    let mut result: String = quoted_re
+        .captures_iter(description)
+        .flat_map(|caps| caps[1].chars().collect::<Vec<_>>())
+        .collect();
+    if result.is_empty() {
+        let description = description.to_lowercase();
+        let is_lower = description.contains("lowercase");
+        let is_upper = description.contains("uppercase");
+        if description.contains("letters") {
+            if is_lower || !is_upper {
+                result.extend('a'..='z');
+            }
+            if is_upper || !is_lower {
+                result.extend('A'..='Z');
+            }
+        }
+        if description.contains("digits") {
+            result.extend('0'..='9');
+        }
+    }
+    let mut seen = vec![];
+    result.retain(|c| {
+        let is_new = !seen.contains(&c);
+        seen.push(c);
+        is_new
+    });
+    (!result.is_empty()).then_some(result)
+}
+
+/// Splits `nums[i].length` into the argument and what is limited
+fn parse_subject(subject: &str) -> Option<(Option<String>, ConstraintTarget)> {
+    let re = Regex::new(r"^([A-Za-z_][A-Za-z_0-9]*)((?:\[[^\]]*\])*)(?:\.(length|val))?$")
+        .expect("compiling static regex");
+    let caps = re.captures(subject)?;
+    let name = &caps[1];
+    let is_indexed = !caps[2].is_empty();
+    let suffix = caps.get(3).map(|x| x.as_str());
+    if name == "Node" {
+        return (suffix == Some("val")).then_some((None, ConstraintTarget::Element));
+    }
+    let target = match (is_indexed, suffix) {
+        (false, None) => ConstraintTarget::Value,
+        (false, Some("length")) => ConstraintTarget::Length,
+        (true, None) => ConstraintTarget::Element,
+        (true, Some("length")) => ConstraintTarget::ElementLength,
+        _ => return None,
+    };
+    Some((Some(name.to_case(Case::Snake)), target))
+}
+
+/// Evaluates integer expressions like `-2 * 10^5` or `2^31 - 1`
+///
+/// Returns `None` if the expression is not made up of only numbers or
+/// overflows
+fn evaluate(expr: &str) -> Option<i64> {
+    evaluate_with_variables(expr, &|_| None)
+}
+
+/// Like [`evaluate`] but variables (e.g. `m` or `nums.length`) are replaced
+/// with their value from `value_of`
+fn evaluate_with_variables(expr: &str, value_of: &dyn Fn(&str) -> Option<i64>) -> Option<i64> {
+    let mut parser = ExprParser {
+        chars: expr.chars().filter(|c| !c.is_whitespace()).collect(),
+        pos: 0,
+        value_of,
+    };
+    let result = parser.sum()?;
+    if parser.pos != parser.chars.len() {
+        return None;
+    }
+    i64::try_from(result).ok()
+}
+
+struct ExprParser<'a> {
+    chars: Vec<char>,
+    pos: usize,
+    value_of: &'a dyn Fn(&str) -> Option<i64>,
+}
+
+impl ExprParser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn sum(&mut self) -> Option<i128> {
+        let mut result = self.product()?;
+        while let Some(op @ ('+' | '-')) = self.peek() {
+            self.pos += 1;
+            let rhs = self.product()?;
+            result = if op == '+' {
+                result.checked_add(rhs)?
+            } else {
+                result.checked_sub(rhs)?
+            };
+        }
+        Some(result)
+    }
+
+    fn product(&mut self) -> Option<i128> {
+        let mut result = self.unary()?;
+        while let Some('*' | '×') = self.peek() {
+            self.pos += 1;
+            result = result.checked_mul(self.unary()?)?;
+        }
+        Some(result)
+    }
+
+    fn unary(&mut self) -> Option<i128> {
+        if self.peek() == Some('-') {
+            self.pos += 1;
+            return self.unary()?.checked_neg();
+        }
+        self.power()
+    }
+
+    fn power(&mut self) -> Option<i128> {
+        let base = self.atom()?;
+        if self.peek() == Some('^') {
+            self.pos += 1;
+            let exponent = u32::try_from(self.unary()?).ok()?;
+            return base.checked_pow(exponent);
+        }
+        Some(base)
+    }
+
+    fn atom(&mut self) -> Option<i128> {
+        if self.peek() == Some('(') {
+            self.pos += 1;
+            let result = self.sum()?;
+            if self.peek() != Some(')') {
+                return None;
+            }
+            self.pos += 1;
+            return Some(result);
+        }
+        let start = self.pos;
+        if self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        {
+            while self
+                .peek()
+                .is_some_and(|c| c.is_ascii_alphanumeric() || "_.[]".contains(c))
+            {
+                self.pos += 1;
+            }
+            let variable: String = self.chars[start..self.pos].iter().collect();
+            return (self.value_of)(&variable).map(i128::from);
+        }
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos]
+            .iter()
+            .collect::<String>()
+            .parse()
+            .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("10^4", Some(10_000))]
+    #[case("-10^9", Some(-1_000_000_000))]
+    #[case("2 * 10^5", Some(200_000))]
+    #[case("2^31 - 1", Some(2_147_483_647))]
+    #[case("-2^31", Some(-2_147_483_648))]
+    #[case("(10^5)", Some(100_000))]
+    #[case("word.length - 5", None)]
+    #[case("10^30", None)]
+    fn evaluate_expressions(#[case] expr: &str, #[case] expected: Option<i64>) {
+        assert_eq!(evaluate(expr), expected);
+    }
+
+    fn constraint(
+        identifier: Option<&str>,
+        target: ConstraintTarget,
+        min: Option<i64>,
+        max: Option<i64>,
+    ) -> Constraint {
+        Constraint {
+            identifier: identifier.map(ToString::to_string),
+            target,
+            min,
+            max,
+            is_max_relative: false,
+            characters: None,
+        }
+    }
+
+    fn characters(identifier: &str, characters: &str) -> Constraint {
+        Constraint {
+            characters: Some(characters.to_string()),
+            ..constraint(Some(identifier), ConstraintTarget::Characters, None, None)
+        }
+    }
+
+    #[rstest]
+    #[case(
+        "`2 <= nums.length <= 10^4`",
+        vec![constraint(Some("nums"), ConstraintTarget::Length, Some(2), Some(10_000))],
+    )]
+    #[case(
+        "`0 <= image[i][j], color < 2^16`",
+        vec![
+            constraint(Some("image"), ConstraintTarget::Element, Some(0), Some(65_535)),
+            constraint(Some("color"), ConstraintTarget::Value, Some(0), Some(65_535)),
+        ],
+    )]
+    #[case(
+        "`1 <= grid[i].length, numCourses <= 200`",
+        vec![
+            constraint(Some("grid"), ConstraintTarget::ElementLength, Some(1), Some(200)),
+            constraint(Some("num_courses"), ConstraintTarget::Value, Some(1), Some(200)),
+        ],
+    )]
+    #[case(
+        "The number of nodes in each linked list is in the range `[1, 100]`.",
+        vec![constraint(None, ConstraintTarget::Length, Some(1), Some(100))],
+    )]
+    #[case(
+        "`-2^31 <= Node.val <= 2^31 - 1`",
+        vec![constraint(None, ConstraintTarget::Element, Some(-2_147_483_648), Some(2_147_483_647))],
+    )]
+    #[case(
+        "`0 <= k <= word.length - 5`",
+        vec![constraint(Some("k"), ConstraintTarget::Value, Some(0), None)],
+    )]
+    #[case(
+        "`s` consists of parentheses only `'()[]{}'`.",
+        vec![characters("s", "()[]{}")],
+    )]
+    #[case(
+        "`pattern` consists of only the letters `'I'` and `'D'`.",
+        vec![characters("pattern", "ID")],
+    )]
+    #[case(
+        "`s` and `t` consist of lowercase English letters.",
+        vec![characters("s", "abcdefghijklmnopqrstuvwxyz"), characters("t", "abcdefghijklmnopqrstuvwxyz")],
+    )]
+    #[case(
+        "`words[i]` consists of digits.",
+        vec![characters("words", "0123456789")],
+    )]
+    #[case("`n == image.length`", vec![])]
+    fn parse_lines(#[case] line: &str, #[case] expected: Vec<Constraint>) {
+        assert_eq!(
+            parse_constraint_line(&clean_line(line), &|_| None),
+            expected
+        );
+    }
+
+    #[test]
+    fn aliases_and_relative_limits() {
+        let markdown = "**Constraints:**
+
+- `m == image.length`
+- `n == image[i].length`
+- `1 <= m, n <= 50`
+- `0 <= sr < m`
+- `0 <= k <= n - 2`
+";
+        let relative = |identifier, max| Constraint {
+            is_max_relative: true,
+            ..constraint(
+                Some(identifier),
+                ConstraintTarget::Value,
+                Some(0),
+                Some(max),
+            )
+        };
+        assert_eq!(
+            parse_constraints(markdown),
+            [
+                constraint(Some("image"), ConstraintTarget::Length, Some(1), Some(50)),
+                constraint(
+                    Some("image"),
+                    ConstraintTarget::ElementLength,
+                    Some(1),
+                    Some(50)
+                ),
+                relative("sr", 49),
+                relative("k", 48),
+            ]
+        );
+    }
+}
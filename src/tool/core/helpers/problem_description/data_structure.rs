@@ -1,3 +1,8 @@
+use super::{
+    constraints::{Constraint, parse_constraints},
+    examples::{Example, parse_examples},
+    markdown::html_to_markdown,
+};
 use log::info;
 use regex::Regex;
 
@@ -22,24 +27,39 @@ pub(crate) struct ProblemDescription {
 }
 
 impl ProblemDescription {
+    pub(crate) fn get_constraints(&self) -> Vec<Constraint> {
+        info!("Extracting constraints from description");
+        parse_constraints(&self.to_markdown())
+    }
+
+    pub(crate) fn get_examples(&self) -> Vec<Example> {
+        info!("Extracting examples from description");
+        parse_examples(&self.content)
+    }
+
+    /// Returns true if the description says the answer can be returned in
+    /// any order
+    pub(crate) fn is_any_order_accepted(&self) -> bool {
+        let re = Regex::new(r"(?i)\bin any order\b").expect("compiling static regex");
+        re.is_match(&self.content)
+    }
+
     pub(crate) fn get_solutions(&self) -> Vec<String> {
         info!("Extracting solutions from description");
-        let re = Regex::new(r"Output:<\/strong> (.+?)\n").expect("compiling static regex");
-        re.captures_iter(&self.content)
-            .map(|cap| {
-                let sol = &cap[1];
-
-                // Remove any tags captured
-                let re = Regex::new(r"<.+?>").expect("compiling static regex");
-                let sol = re.replace_all(sol, "");
-
-                // Replace Quote HTML Character Entity with literal
-                sol.replace("&quot;", "\"")
-            })
+        self.get_examples()
+            .into_iter()
+            .map(|example| example.output)
             .collect()
     }
 }
 
+impl ProblemDescription {
+    /// Returns the description converted into markdown
+    pub(crate) fn to_markdown(&self) -> String {
+        html_to_markdown(&self.content)
+    }
+}
+
 impl TryFrom<ProblemDescriptionResponse> for ProblemDescription {
     type Error = anyhow::Error;
 
@@ -0,0 +1,170 @@
+//! Extraction of the examples from the html of a problem description
+//!
+//! Leetcode uses two layouts for examples, older problems put each example in
+//! a `<pre>` and newer ones use `<div class="example-block">` with a `<p>` per
+//! field. Both are reduced to plain text lines and then split on the labels
+//! (`Input:`, `Output:` and `Explanation:`).
+
+use regex::Regex;
+
+use super::markdown::decode_entities;
+
+/// One example from the description of a problem
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct Example {
+    pub(crate) input: String,
+    pub(crate) output: String,
+    pub(crate) explanation: Option<String>,
+}
+
+#[derive(Clone, Copy)]
+enum Field {
+    Input,
+    Output,
+    Explanation,
+}
+
+impl Field {
+    const ALL: [Self; 3] = [Self::Input, Self::Output, Self::Explanation];
+
+    const fn label(self) -> &'static str {
+        match self {
+            Self::Input => "Input:",
+            Self::Output => "Output:",
+            Self::Explanation => "Explanation:",
+        }
+    }
+}
+
+/// Returns the examples in the order they appear in `html`
+///
+/// Blocks without an output (for example a `<pre>` used for something else)
+/// are skipped
+pub(crate) fn parse_examples(html: &str) -> Vec<Example> {
+    let re = Regex::new(r#"(?s)<pre>(.*?)</pre>|<div class="example-block">"#)
+        .expect("compiling static regex");
+    let mut result = vec![];
+    let mut start = 0;
+    while let Some(caps) = re.captures_at(html, start) {
+        let whole = caps.get(0).expect("group 0 is always present");
+        let block = if let Some(pre) = caps.get(1) {
+            start = whole.end();
+            pre.as_str()
+        } else {
+            // The block may contain other divs so the end is found by nesting
+            let content = &html[whole.end()..];
+            let len = find_closing_div(content).unwrap_or(content.len());
+            start = whole.end() + len;
+            &content[..len]
+        };
+        result.extend(parse_example(&to_plain_text(block)));
+    }
+    result
+}
+
+/// Returns the position of the `</div>` that closes the div `content` is in,
+/// `None` if it is not closed
+fn find_closing_div(content: &str) -> Option<usize> {
+    let re = Regex::new(r"(?i)<(/?)div\b").expect("compiling static regex");
+    let mut depth = 0usize;
+    for caps in re.captures_iter(content) {
+        if caps[1].is_empty() {
+            depth += 1;
+        } else if depth == 0 {
+            return caps.get(0).map(|tag| tag.start());
+        } else {
+            depth -= 1;
+        }
+    }
+    None
+}
+
+/// Removes the tags from an example block keeping one line per paragraph
+fn to_plain_text(block: &str) -> String {
+    let re = Regex::new(r"(?i)<br\s*/?>|</p>").expect("compiling static regex");
+    let block = re.replace_all(block, "\n");
+    let re = Regex::new(r"(?s)<.+?>").expect("compiling static regex");
+    decode_entities(&re.replace_all(&block, ""))
+}
+
+fn parse_example(text: &str) -> Option<Example> {
+    let mut fields: [Vec<&str>; 3] = Default::default();
+    let mut current = None;
+    for line in text.lines().map(str::trim) {
+        if let Some((field, rest)) = Field::ALL
+            .iter()
+            .find_map(|field| Some((*field, line.strip_prefix(field.label())?)))
+        {
+            current = Some(field);
+            fields[field as usize].push(rest.trim());
+        } else if let Some(field) = current {
+            fields[field as usize].push(line);
+        }
+    }
+
+    let [input, output, explanation] = fields.map(|lines| {
+        lines
+            .into_iter()
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+    });
+    if output.is_empty() {
+        return None;
+    }
+    Some(Example {
+        input: input.join("\n"),
+        // Long outputs are sometimes split over multiple lines
+        output: output.join(" "),
+        explanation: (!explanation.is_empty()).then(|| explanation.join("\n")),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::pre(
+        "<pre>\n<strong>Input:</strong> s = &quot;()&quot;\n<strong>Output:</strong> true\n</pre>",
+        Example { input: r#"s = "()""#.into(), output: "true".into(), explanation: None }
+    )]
+    #[case::pre_with_explanation(
+        "<pre>\n<strong>Input:</strong> a = 1\n<strong>Output:</strong> &quot;a&lt;b&gt;&amp;&#39;&quot;\n<strong>Explanation:\n</strong>Line one.\nLine two.</pre>",
+        Example {
+            input: "a = 1".into(),
+            output: r#""a<b>&'""#.into(),
+            explanation: Some("Line one.\nLine two.".into()),
+        }
+    )]
+    #[case::example_block(
+        "<div class=\"example-block\">\n<p><strong>Input:</strong> <span class=\"example-io\">n = 2</span></p>\n\n<p><strong>Output:</strong> <span class=\"example-io\">[[1,2],[3,4]]</span></p>\n\n<p><strong>Explanation:</strong></p>\n\n<p><img src=\"a.jpg\" /></p>\n\n<p>Some <code>code</code>.</p>\n</div>",
+        Example {
+            input: "n = 2".into(),
+            output: "[[1,2],[3,4]]".into(),
+            explanation: Some("Some code.".into()),
+        }
+    )]
+    #[case::multi_line_output(
+        "<pre>\n<b>Input:</b> n = 2\n<b>Output:</b> [[1,2],\n[3,4]]\n</pre>",
+        Example { input: "n = 2".into(), output: "[[1,2], [3,4]]".into(), explanation: None }
+    )]
+    #[case::example_block_with_nested_div(
+        "<div class=\"example-block\">\n<p><strong>Input:</strong> x = 1</p>\n<div class=\"note\"><p>Note</p></div>\n<p><strong>Output:</strong> 2</p>\n</div>",
+        Example { input: "x = 1\nNote".into(), output: "2".into(), explanation: None }
+    )]
+    fn single_example(#[case] html: &str, #[case] expected: Example) {
+        assert_eq!(parse_examples(html), [expected]);
+    }
+
+    #[test]
+    fn block_without_output_skipped() {
+        let html = "<pre>\nSome diagram\n</pre>\n<pre>\n<strong>Input:</strong> x = 1\n<strong>Output:</strong> 2\n</pre>";
+        let actual: Vec<_> = parse_examples(html)
+            .into_iter()
+            .map(|example| example.output)
+            .collect();
+        assert_eq!(actual, ["2"]);
+    }
+}
@@ -0,0 +1,339 @@
+//! Conversion of the html used in problem descriptions into markdown suitable
+//! for rustdoc
+//!
+//! Only the subset of html used by leetcode is supported, unknown tags are
+//! removed and their text kept
+
+use std::fmt::Write as _;
+
+use regex::Regex;
+
+/// Converts `html` into markdown
+pub(crate) fn html_to_markdown(html: &str) -> String {
+    let mut converter = Converter::default();
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        converter.text(&rest[..start]);
+        let Some(len) = rest[start..].find('>') else {
+            // Not a tag so keep as text
+            converter.text(&rest[start..]);
+            rest = "";
+            break;
+        };
+        converter.tag(&rest[start + 1..start + len]);
+        rest = &rest[start + len + 1..];
+    }
+    converter.text(rest);
+    converter.finish()
+}
+
+#[derive(Default)]
+struct Converter {
+    output: String,
+    /// Number of open `<pre>` tags (whitespace is kept and formatting ignored)
+    pre_depth: usize,
+    /// Number of open `<code>` tags outside of `<pre>`
+    code_depth: usize,
+    /// Open lists, innermost last
+    lists: Vec<List>,
+    /// Destinations of open links (`None` if the link has no destination)
+    links: Vec<Option<String>>,
+}
+
+/// A `<ul>` or `<ol>` being converted
+struct List {
+    /// Number of the next item for ordered lists
+    next_number: Option<usize>,
+    /// Column where the text of the current item starts (after its marker)
+    content_indent: usize,
+}
+
+impl Converter {
+    const PRE_OPEN: &str = "```text\n";
+    const PRE_CLOSE: &str = "```";
+
+    fn tag(&mut self, tag: &str) {
+        let tag = tag.trim();
+        let is_closing = tag.starts_with('/');
+        let tag = tag.trim_start_matches('/');
+        let name = tag
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        let is_formatting_allowed = self.pre_depth == 0 && self.code_depth == 0;
+
+        match (name.as_str(), is_closing) {
+            ("pre", false) => {
+                self.ensure_blank_line();
+                self.output.push_str(Self::PRE_OPEN);
+                self.pre_depth += 1;
+            }
+            ("pre", true) => {
+                self.pre_depth = self.pre_depth.saturating_sub(1);
+                self.ensure_newline();
+                self.output.push_str(Self::PRE_CLOSE);
+                self.ensure_blank_line();
+            }
+            ("code", _) if self.pre_depth == 0 => {
+                self.output.push('`');
+                if is_closing {
+                    self.code_depth = self.code_depth.saturating_sub(1);
+                } else {
+                    self.code_depth += 1;
+                }
+            }
+            ("strong" | "b", _) if is_formatting_allowed => self.emphasis("**", is_closing),
+            ("em" | "i", _) if is_formatting_allowed => self.emphasis("*", is_closing),
+            ("sup", false) => self.output.push('^'),
+            ("sub", false) => self
+                .output
+                .push_str(if is_formatting_allowed { "\\_" } else { "_" }),
+            ("br", _) => self.ensure_newline(),
+            ("p" | "div", _) if self.pre_depth == 0 => self.ensure_blank_line(),
+            ("ul" | "ol", false) => {
+                if self.lists.is_empty() {
+                    self.ensure_blank_line();
+                } else {
+                    self.ensure_newline();
+                }
+                self.lists.push(List {
+                    next_number: (name == "ol").then_some(1),
+                    content_indent: 0,
+                });
+            }
+            ("ul" | "ol", true) => {
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.ensure_blank_line();
+                }
+            }
+            ("li", false) => {
+                self.ensure_newline();
+                // Nested lists are indented to the content of the parent item
+                // (e.g. 3 spaces under "1. ") as required by CommonMark
+                let indent = self
+                    .lists
+                    .iter()
+                    .rev()
+                    .nth(1)
+                    .map_or(0, |parent| parent.content_indent);
+                let marker = match self.lists.last_mut() {
+                    Some(List {
+                        next_number: Some(number),
+                        ..
+                    }) => {
+                        let marker = format!("{number}. ");
+                        *number += 1;
+                        marker
+                    }
+                    _ => "- ".to_string(),
+                };
+                write!(self.output, "{:indent$}{marker}", "").expect("write! macro failed");
+                if let Some(list) = self.lists.last_mut() {
+                    list.content_indent = indent + marker.len();
+                }
+            }
+            ("img", _) => {
+                let alt = attribute(tag, "alt").unwrap_or_default();
+                if let Some(src) = attribute(tag, "src") {
+                    self.ensure_blank_line();
+                    write!(self.output, "![{alt}]({src})").expect("write! macro failed");
+                    self.ensure_blank_line();
+                }
+            }
+            ("a", false) if is_formatting_allowed => {
+                let href = attribute(tag, "href");
+                if href.is_some() {
+                    self.output.push('[');
+                }
+                self.links.push(href);
+            }
+            ("a", true) if is_formatting_allowed => {
+                if let Some(Some(href)) = self.links.pop() {
+                    write!(self.output, "]({href})").expect("write! macro failed");
+                }
+            }
+            _ => {} // Unsupported tags are removed
+        }
+    }
+
+    fn text(&mut self, raw: &str) {
+        let decoded = decode_entities(raw);
+        if self.pre_depth > 0 {
+            let text = if self.output.ends_with(Self::PRE_OPEN) {
+                decoded.trim_start_matches('\n')
+            } else {
+                &decoded
+            };
+            self.output.push_str(text);
+            return;
+        }
+
+        // Outside of `<pre>` all whitespace is equivalent to a single space
+        let mut text = String::with_capacity(decoded.len());
+        for c in decoded.chars() {
+            if !c.is_whitespace() {
+                text.push(c);
+            } else if !text.ends_with(' ') {
+                text.push(' ');
+            }
+        }
+        if self.output.is_empty() || self.output.ends_with(['\n', ' ']) {
+            text = text.trim_start().to_string();
+        }
+
+        if self.code_depth == 0 {
+            text = escape(&text);
+        }
+        self.output.push_str(&text);
+    }
+
+    /// Adds the emphasis `marker` keeping any whitespace outside of it as
+    /// markdown does not allow it inside
+    fn emphasis(&mut self, marker: &str, is_closing: bool) {
+        if is_closing {
+            let trimmed_len = self.output.trim_end().len();
+            let whitespace = self.output.split_off(trimmed_len);
+            self.output.push_str(marker);
+            self.output.push_str(&whitespace);
+        } else {
+            self.output.push_str(marker);
+        }
+    }
+
+    fn ensure_newline(&mut self) {
+        self.trim_trailing_spaces();
+        if !self.output.is_empty() && !self.output.ends_with('\n') {
+            self.output.push('\n');
+        }
+    }
+
+    fn ensure_blank_line(&mut self) {
+        self.ensure_newline();
+        if !self.output.is_empty() && !self.output.ends_with("\n\n") {
+            self.output.push('\n');
+        }
+    }
+
+    fn trim_trailing_spaces(&mut self) {
+        let trimmed_len = self.output.trim_end_matches([' ', '\t']).len();
+        self.output.truncate(trimmed_len);
+    }
+
+    fn finish(self) -> String {
+        // Remove trailing whitespace and collapse runs of blank lines
+        let mut result = String::with_capacity(self.output.len());
+        let mut was_blank = true;
+        for line in self.output.lines().map(str::trim_end) {
+            let is_blank = line.is_empty();
+            if is_blank && was_blank {
+                continue;
+            }
+            result.push_str(line);
+            result.push('\n');
+            was_blank = is_blank;
+        }
+        result.trim_end().to_string()
+    }
+}
+
+/// Gets the value of the attribute `name` from the inside of a tag
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let re = Regex::new(&format!(r#"\b{name}\s*=\s*"([^"]*)""#)).expect("compiling regex");
+    re.captures(tag).map(|caps| decode_entities(&caps[1]))
+}
+
+/// Escapes characters that markdown (or rustdoc) would otherwise interpret
+fn escape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>') {
+            result.push('\\');
+        }
+        result.push(c);
+    }
+    result
+}
+
+/// Replaces html character entities with the characters they represent
+pub(super) fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    let re =
+        Regex::new(r"&(#[0-9]+|#[xX][0-9a-fA-F]+|[a-zA-Z]+);").expect("compiling static regex");
+    re.replace_all(text, |caps: &regex::Captures| {
+        let entity = &caps[1];
+        let decoded = match entity {
+            "nbsp" => Some(' '),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "amp" => Some('&'),
+            "le" => Some('≤'),
+            "ge" => Some('≥'),
+            "ne" => Some('≠'),
+            "times" => Some('×'),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .map_or_else(
+                    || entity.strip_prefix('#').and_then(|x| x.parse().ok()),
+                    |hex| u32::from_str_radix(hex, 16).ok(),
+                )
+                .and_then(char::from_u32),
+        };
+        decoded.map_or_else(|| caps[0].to_string(), String::from)
+    })
+    .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::paragraphs("<p>One</p>\n\n<p>Two</p>", "One\n\nTwo")]
+    #[case::emphasis(
+        "<p>Use <strong>exactly <em>one</em></strong> and <b>Note:&nbsp;</b>here</p>",
+        "Use **exactly *one*** and **Note:** here"
+    )]
+    #[case::inline_code(
+        "<code>2 &lt;= nums.length &lt;= 10<sup>4</sup></code>",
+        "`2 <= nums.length <= 10^4`"
+    )]
+    #[case::escaped_text("return nums[0] * 2 &lt; x_y", r"return nums\[0\] \* 2 \< x\_y")]
+    #[case::pre(
+        "<pre>\n<strong>Input:</strong> s = \"()\"\n<strong>Output:</strong> true\n</pre>",
+        "```text\nInput: s = \"()\"\nOutput: true\n```"
+    )]
+    #[case::unordered_list(
+        "<ul>\n\t<li><code>1 &lt;= n</code></li>\n\t<li>Only one.</li>\n</ul>",
+        "- `1 <= n`\n- Only one."
+    )]
+    #[case::ordered_nested_list(
+        "<ol><li>First<ul><li>Inner</li></ul></li><li>Second</li></ol>",
+        "1. First\n   - Inner\n2. Second"
+    )]
+    #[case::nested_under_unordered(
+        "<ul><li>A<ol><li>B<ul><li>C</li></ul></li></ol></li></ul>",
+        "- A\n  1. B\n     - C"
+    )]
+    #[case::image(
+        r#"<img alt="" src="https://assets.leetcode.com/a.jpg" style="width: 1px;" />"#,
+        "![](https://assets.leetcode.com/a.jpg)"
+    )]
+    #[case::link(r#"<a href="https://x.com/a">here</a>"#, "[here](https://x.com/a)")]
+    #[case::entities("&quot;a&#39;b&#x27;&amp;&unknown;", r#""a'b'&&unknown;"#)]
+    #[case::unknown_tag(
+        r#"O(n<sup>2</sup>)<font face="monospace">&nbsp;</font>time"#,
+        "O(n^2) time"
+    )]
+    fn converts_html(#[case] html: &str, #[case] expected: &str) {
+        assert_eq!(html_to_markdown(html), expected);
+    }
+}
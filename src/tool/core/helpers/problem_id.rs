@@ -0,0 +1,136 @@
+use anyhow::{Context, bail};
+use log::info;
+
+use crate::tool::config::Config;
+
+use super::{get_response, local_store::path_local_store_problem_id};
+
+#[derive(serde::Deserialize, Debug)]
+struct ProblemIdResponse {
+    data: Data,
+}
+impl ProblemIdResponse {
+    fn into_title_slug(self, id: &str) -> anyhow::Result<String> {
+        // The search also matches on titles and ids that contain the id
+        let Some(question) = self
+            .data
+            .problemset_question_list
+            .questions
+            .into_iter()
+            .find(|question| question.frontend_question_id == id)
+        else {
+            bail!("no problem found with id {id}")
+        };
+        Ok(question.title_slug)
+    }
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct Data {
+    #[serde(rename = "problemsetQuestionList")]
+    problemset_question_list: ProblemsetQuestionList,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct ProblemsetQuestionList {
+    questions: Vec<Question>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct Question {
+    #[serde(rename = "frontendQuestionId")]
+    frontend_question_id: String,
+    #[serde(rename = "titleSlug")]
+    title_slug: String,
+}
+
+pub(crate) fn get_slug_from_problem_id(id: u16) -> anyhow::Result<String> {
+    info!("Attempting to get slug for problem id {id}");
+    let id = id.to_string();
+    get_problem_id_response(&id)?.into_title_slug(&id)
+}
+
+fn get_problem_id_response(id: &str) -> anyhow::Result<ProblemIdResponse> {
+    get_response(
+        id,
+        local_store_request_problem_id,
+        external_request_problem_id,
+    )
+}
+
+fn local_store_request_problem_id(id: &str) -> anyhow::Result<String> {
+    let path = path_local_store_problem_id(id);
+    std::fs::read_to_string(&path).with_context(|| format!("failed to read string from {path:?}"))
+}
+
+fn external_request_problem_id(id: &str) -> anyhow::Result<String> {
+    info!("[External] Going to send request for slug of problem with id: {id}");
+    ureq::post(Config::LEETCODE_GRAPH_QL)
+        .send_json(serde_json::json!({
+            "query": r"query problemsetQuestionList($categorySlug: String, $limit: Int, $skip: Int, $filters: QuestionListFilterInput) {
+            problemsetQuestionList: questionList(categorySlug: $categorySlug, limit: $limit, skip: $skip, filters: $filters) {
+                questions: data {
+                    frontendQuestionId: questionFrontendId
+                    titleSlug
+                }
+            }
+        }",
+            "variables":{"categorySlug": "", "skip": 0, "limit": 50, "filters": {"searchKeywords": id}},
+            "operationName":"problemsetQuestionList"
+        }))
+        .context("failed to get request for problem id")?
+        .body_mut()
+        .read_to_string()
+        .context("failed to convert response into String")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write as _;
+
+    use anyhow::Context;
+    use rstest::{fixture, rstest};
+
+    use crate::tool::core::helpers::{
+        local_store::{path_local_store_problem_id, tests::get_rnd_request_delay},
+        problem_id::{external_request_problem_id, get_slug_from_problem_id},
+    };
+
+    #[fixture]
+    fn problem_ids() -> &'static [(u16, &'static str)] {
+        &[(1, "two-sum"), (20, "valid-parentheses")]
+    }
+
+    #[rstest]
+    #[ignore = "Only use for downloading responses"]
+    fn download_response_from_leetcode(problem_ids: &[(u16, &str)]) {
+        for (id, _) in problem_ids {
+            let sleep_delay = std::time::Duration::from_millis(get_rnd_request_delay());
+            println!(
+                "Going to sleep for {} milliseconds before requesting and trying to save {id}",
+                sleep_delay.as_millis()
+            );
+            std::thread::sleep(sleep_delay); // Sleep to not go too hard on leetcode API
+            let response_string = external_request_problem_id(&id.to_string()).unwrap();
+            let path = path_local_store_problem_id(id.to_string());
+            let mut file = std::fs::OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(&path)
+                .with_context(|| format!("failed to save response to {path:?}"))
+                .unwrap();
+            file.write_all(response_string.as_bytes()).unwrap();
+            println!("Save of {id:?} completed\n");
+        }
+        println!("Successfully saved all responses");
+    }
+
+    #[rstest]
+    fn slug_from_problem_id(problem_ids: &[(u16, &str)]) {
+        for &(id, expected) in problem_ids {
+            let actual = get_slug_from_problem_id(id).unwrap();
+            assert_eq!(actual, expected);
+        }
+    }
+}
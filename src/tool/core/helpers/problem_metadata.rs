@@ -41,6 +41,11 @@ fn external_request_problem_metadata(title_slug: &str) -> anyhow::Result<String>
                 questionFrontendId
                 questionTitle
                 exampleTestcaseList
+                difficulty
+                topicTags {
+                    name
+                    slug
+                }
             }
         }",
             "variables":{"titleSlug": title_slug},
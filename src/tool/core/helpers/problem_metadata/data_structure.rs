@@ -1,6 +1,9 @@
-use super::super::problem_code::{FunctionInfo, ProblemCode};
-use crate::tool::core::helpers::{
-    problem_code::ProblemType, problem_description::data_structure::ProblemDescription,
+use super::super::problem_code::{ConcurrencyInfo, FunctionInfo, ProblemCode};
+use crate::tool::{
+    config_file::TestStyle,
+    core::helpers::{
+        problem_code::ProblemType, problem_description::data_structure::ProblemDescription,
+    },
 };
 use anyhow::Context;
 use log::{error, info};
@@ -29,13 +32,24 @@ struct Question {
     title: String,
     #[serde(rename = "exampleTestcaseList")]
     example_test_case_list: Vec<String>,
+    #[serde(default)]
+    difficulty: Option<String>,
+    #[serde(rename = "topicTags", default)]
+    topic_tags: Vec<TopicTag>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct TopicTag {
+    name: String,
 }
 
 #[derive(Debug)]
 pub(crate) struct ProblemMetadata {
     pub(crate) id: u16,
-    title: String,
-    example_test_case_list: Vec<String>,
+    pub(crate) title: String,
+    pub(crate) example_test_case_list: Vec<String>,
+    pub(crate) difficulty: Option<String>,
+    pub(crate) topic_tags: Vec<String>,
 }
 
 impl TryFrom<Question> for ProblemMetadata {
@@ -46,19 +60,20 @@ impl TryFrom<Question> for ProblemMetadata {
             id: value.id.parse().context("failed to parse id")?,
             title: value.title,
             example_test_case_list: value.example_test_case_list,
+            difficulty: value.difficulty,
+            topic_tags: value.topic_tags.into_iter().map(|tag| tag.name).collect(),
         })
     }
 }
 
 impl ProblemMetadata {
-    pub(crate) fn get_num_and_title(&self) -> String {
-        format!("{}. {}", self.id, self.title)
-    }
-
     pub(crate) fn get_test_cases(
         &self,
         problem_code: &ProblemCode,
         description: &ProblemDescription,
+        test_style: TestStyle,
+        should_include_stress_test: bool,
+        should_include_fuzz_test: bool,
     ) -> String {
         info!("Going to get tests");
 
@@ -76,9 +91,27 @@ impl ProblemMetadata {
 
                 // Add actual test cases
                 let solutions = description.get_solutions();
-                self.get_test_cases_is_not_design(fn_info, solutions)
+                let mut tests = self.get_test_cases_is_not_design(fn_info, solutions, test_style);
+
+                if let Some(check_answer) = fn_info.get_check_answer_fn() {
+                    tests.push_str("\n\n    ");
+                    tests.push_str(&check_answer);
+                }
+                if should_include_stress_test && let Some(stress_test) = fn_info.get_stress_test() {
+                    tests.push_str("\n\n    ");
+                    tests.push_str(&stress_test);
+                }
+                if should_include_fuzz_test && let Some(fuzz_test) = fn_info.get_fuzz_test() {
+                    tests.push_str("\n\n    ");
+                    tests.push_str(&fuzz_test);
+                }
+                tests
             }
             ProblemType::Design => self.get_test_cases_is_design(),
+            ProblemType::Concurrency(info) => {
+                imports.push_str(ConcurrencyInfo::get_imports());
+                info.get_tests(&self.example_test_case_list, &description.get_solutions())
+            }
         };
 
         format!(
@@ -98,13 +131,8 @@ mod tests {{
         &self,
         fn_info: &FunctionInfo,
         mut solutions: Vec<String>,
+        test_style: TestStyle,
     ) -> String {
-        let mut result = "use rstest::rstest;
-
-    #[rstest]
-"
-        .to_string();
-
         if solutions.len() != self.example_test_case_list.len() {
             error!(
                 "Number of solutions ({}) does not match the number of test cases ({}). Falling back to no solutions. Solutions were: {solutions:?}",
@@ -114,33 +142,125 @@ mod tests {{
             solutions = self
                 .example_test_case_list
                 .iter()
-                .map(|_| "todo!(\"Failed to get solutions\"".to_string())
+                .map(|_| "todo!(\"Failed to get solutions\")".to_string())
                 .collect();
         }
         assert_eq!(solutions.len(), self.example_test_case_list.len());
 
+        let test_cases: Vec<String> = self
+            .example_test_case_list
+            .iter()
+            .zip(solutions)
+            .map(|(example_test_case_raw, solution)| {
+                fn_info.get_test_case(
+                    example_test_case_raw,
+                    &solution,
+                    test_style == TestStyle::Rstest,
+                )
+            })
+            .collect();
+
+        match test_style {
+            TestStyle::Rstest => Self::get_rstest_tests(fn_info, &test_cases),
+            TestStyle::Plain => Self::get_plain_tests(fn_info, &test_cases),
+            TestStyle::Table => Self::get_table_tests(fn_info, &test_cases),
+        }
+    }
+
+    fn get_rstest_tests(fn_info: &FunctionInfo, test_cases: &[String]) -> String {
+        let mut result = "use rstest::rstest;
+
+    #[rstest]
+"
+        .to_string();
+
         // Add test cases
-        for (example_test_case_raw, solution) in self.example_test_case_list.iter().zip(solutions) {
-            let test_case = fn_info.get_test_case(example_test_case_raw, &solution);
+        for test_case in test_cases {
             writeln!(result, "    #[case({test_case})]").expect("write! macro failed");
         }
 
         // Add test case function body
         let test_fn = format!(
             "    fn case({}) {{
-        let actual = Solution::{}({});
         {}
     }}",
             fn_info.get_args_with_case(),
-            fn_info.name,
-            fn_info.get_args_names(),
-            fn_info.get_solution_comparison_code(),
+            fn_info.get_solution_check_code(None),
         );
         result.push_str(&test_fn);
 
         result
     }
 
+    fn get_plain_tests(fn_info: &FunctionInfo, test_cases: &[String]) -> String {
+        let mut result = String::new();
+        for (i, test_case) in test_cases.iter().enumerate() {
+            write!(
+                result,
+                "#[test]
+    fn example_{}() {{
+        check({test_case});
+    }}
+
+    ",
+                i + 1
+            )
+            .expect("write! macro failed");
+        }
+        result.push_str(&Self::get_check_fn(fn_info, false));
+        result
+    }
+
+    fn get_table_tests(fn_info: &FunctionInfo, test_cases: &[String]) -> String {
+        let mut result = format!(
+            "#[test]
+    fn examples() {{
+        let cases: Vec<{}> = vec![
+",
+            fn_info.get_case_tuple_type()
+        );
+        for test_case in test_cases {
+            let trailing_comma = if fn_info.get_case_len() == 1 { "," } else { "" };
+            writeln!(result, "            ({test_case}{trailing_comma}),")
+                .expect("write! macro failed");
+        }
+        let names = fn_info.get_case_names();
+        write!(
+            result,
+            "        ];
+        for (i, {}) in cases.into_iter().enumerate() {{
+            check(i + 1, {names});
+        }}
+    }}
+
+    ",
+            fn_info.get_case_tuple_pattern()
+        )
+        .expect("write! macro failed");
+        result.push_str(&Self::get_check_fn(fn_info, true));
+        result
+    }
+
+    /// Function shared by the tests of each example that runs the solution and
+    /// checks the result
+    ///
+    /// If `has_example_number` it takes the number of the example first to
+    /// name it when the check fails
+    fn get_check_fn(fn_info: &FunctionInfo, has_example_number: bool) -> String {
+        format!(
+            "fn check({}{}) {{
+        {}
+    }}",
+            if has_example_number {
+                "example: usize, "
+            } else {
+                ""
+            },
+            fn_info.get_args_with_types(),
+            fn_info.get_solution_check_code(has_example_number.then_some("example")),
+        )
+    }
+
     #[allow(clippy::unused_self, clippy::missing_const_for_fn)] // TODO Onè: implement question type from leetcode
     // see: https://leetcode.com/tag/design/
     fn get_test_cases_is_design(&self) -> String {
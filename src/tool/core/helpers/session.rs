@@ -0,0 +1,60 @@
+//! Credentials needed for requests that require the user to be logged in
+
+use std::env;
+
+use anyhow::Context;
+
+use crate::tool::config::Config;
+
+/// Cookies from a logged in browser session on leetcode
+pub(crate) struct Session {
+    session: String,
+    csrf_token: String,
+}
+
+impl Session {
+    /// Loads the session from the environment variables
+    pub(crate) fn from_env() -> anyhow::Result<Self> {
+        let session = env::var(Config::ENV_VAR_SESSION).with_context(|| {
+            format!(
+                "failed to read {:?}. Set it to the value of the LEETCODE_SESSION cookie from a logged in browser",
+                Config::ENV_VAR_SESSION
+            )
+        })?;
+        let csrf_token = env::var(Config::ENV_VAR_CSRF_TOKEN).with_context(|| {
+            format!(
+                "failed to read {:?}. Set it to the value of the csrftoken cookie from a logged in browser",
+                Config::ENV_VAR_CSRF_TOKEN
+            )
+        })?;
+        Ok(Self {
+            session,
+            csrf_token,
+        })
+    }
+
+    /// Adds the headers needed for leetcode to accept the request as
+    /// authenticated
+    pub(crate) fn authenticate<B>(
+        &self,
+        request: ureq::RequestBuilder<B>,
+    ) -> ureq::RequestBuilder<B> {
+        request
+            .header(
+                "Cookie",
+                format!(
+                    "LEETCODE_SESSION={}; csrftoken={}",
+                    self.session, self.csrf_token
+                ),
+            )
+            .header("x-csrftoken", &self.csrf_token)
+            .header("Referer", "https://leetcode.com")
+    }
+}
+
+impl std::fmt::Debug for Session {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Do not leak credentials into logs
+        f.debug_struct("Session").finish_non_exhaustive()
+    }
+}
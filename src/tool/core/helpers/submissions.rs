@@ -0,0 +1,139 @@
+use std::collections::HashSet;
+
+use anyhow::Context;
+use log::{debug, info};
+
+use crate::tool::config::Config;
+
+use super::{get_response, local_store::path_local_store_submissions, session::Session};
+
+/// Number of submissions requested per page
+const PAGE_SIZE: usize = 20;
+
+#[derive(serde::Deserialize, Debug)]
+struct SubmissionsResponse {
+    submissions_dump: Vec<Submission>,
+    has_next: bool,
+    last_key: String,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub(crate) struct Submission {
+    pub(crate) title_slug: String,
+    lang: String,
+    status_display: String,
+    pub(crate) code: String,
+}
+
+impl Submission {
+    fn is_accepted_rust(&self) -> bool {
+        self.lang == "rust" && self.status_display == "Accepted"
+    }
+}
+
+/// Returns the most recent accepted rust submission for each problem the user
+/// has solved
+pub(crate) fn get_accepted_rust_submissions() -> anyhow::Result<Vec<Submission>> {
+    info!("Attempting to get accepted rust submissions");
+    let session = if cfg!(test) {
+        None
+    } else {
+        Some(Session::from_env().context("an authenticated session is required")?)
+    };
+
+    let mut all_submissions = vec![];
+    let mut offset = 0;
+    let mut last_key = String::new();
+    loop {
+        let page = get_submissions_response(offset, &last_key, session.as_ref())
+            .with_context(|| format!("failed to get page of submissions at offset {offset}"))?;
+        debug!(
+            "Received {} submissions at offset {offset}",
+            page.submissions_dump.len()
+        );
+        offset += page.submissions_dump.len();
+        all_submissions.extend(page.submissions_dump);
+        if !page.has_next {
+            break;
+        }
+        last_key = page.last_key;
+        std::thread::sleep(std::time::Duration::from_millis(
+            Config::MILLISECONDS_DELAY_BETWEEN_PROBLEMS,
+        ));
+    }
+
+    Ok(latest_accepted_rust_per_problem(all_submissions))
+}
+
+/// Expects submissions to be ordered from newest to oldest (as returned by
+/// leetcode) and keeps the first accepted rust submission for each problem
+fn latest_accepted_rust_per_problem(submissions: Vec<Submission>) -> Vec<Submission> {
+    let mut seen = HashSet::new();
+    submissions
+        .into_iter()
+        .filter(|submission| {
+            submission.is_accepted_rust() && seen.insert(submission.title_slug.clone())
+        })
+        .collect()
+}
+
+fn get_submissions_response(
+    offset: usize,
+    last_key: &str,
+    session: Option<&Session>,
+) -> anyhow::Result<SubmissionsResponse> {
+    get_response(
+        &offset.to_string(),
+        local_store_request_submissions,
+        |offset| {
+            external_request_submissions(
+                offset,
+                last_key,
+                session.context("session required for external request")?,
+            )
+        },
+    )
+}
+
+fn local_store_request_submissions(_needed_to_match_signature: &str) -> anyhow::Result<String> {
+    let path = path_local_store_submissions();
+    std::fs::read_to_string(&path).with_context(|| format!("failed to read string from {path:?}"))
+}
+
+fn external_request_submissions(
+    offset: &str,
+    last_key: &str,
+    session: &Session,
+) -> anyhow::Result<String> {
+    info!("[External] Going to send request for submissions at offset {offset}");
+    session
+        .authenticate(ureq::get(Config::LEETCODE_SUBMISSIONS_API))
+        .query("offset", offset)
+        .query("limit", PAGE_SIZE.to_string())
+        .query("lastkey", last_key)
+        .call()
+        .context("failed to get request for submissions")?
+        .body_mut()
+        .read_to_string()
+        .context("failed to convert response into String")
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::tool::core::helpers::local_store::tests::insta_settings;
+
+    use super::*;
+
+    #[rstest]
+    fn keeps_only_latest_accepted_rust_submission(insta_settings: insta::Settings) {
+        // Arrange / Act
+        let actual = get_accepted_rust_submissions().unwrap();
+
+        // Assert
+        insta_settings.bind(|| {
+            insta::assert_debug_snapshot!(actual);
+        });
+    }
+}
@@ -0,0 +1,105 @@
+//! Minimal template engine used to lay out generated modules
+//!
+//! Placeholders are written as `{{name}}` and may have a filter applied with
+//! `{{name | filter}}`. Supported filters:
+//! - `doc`: prefixes each line with `//! ` so the value can be used in the
+//!   module level documentation
+
+use std::collections::HashMap;
+
+use anyhow::{Context, bail};
+
+const OPEN: &str = "{{";
+const CLOSE: &str = "}}";
+
+/// Replaces all placeholders in `template` with the matching value
+pub(crate) fn render(template: &str, values: &HashMap<&str, String>) -> anyhow::Result<String> {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find(OPEN) {
+        result.push_str(&rest[..start]);
+        let after_open = &rest[start + OPEN.len()..];
+        let Some(end) = after_open.find(CLOSE) else {
+            bail!("unterminated placeholder starting at: {:?}", &rest[start..]);
+        };
+        let placeholder = &after_open[..end];
+        result.push_str(
+            &render_placeholder(placeholder, values)
+                .with_context(|| format!("failed to render placeholder {{{{{placeholder}}}}}"))?,
+        );
+        rest = &after_open[end + CLOSE.len()..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+fn render_placeholder(placeholder: &str, values: &HashMap<&str, String>) -> anyhow::Result<String> {
+    let (name, filter) = match placeholder.split_once('|') {
+        Some((name, filter)) => (name.trim(), Some(filter.trim())),
+        None => (placeholder.trim(), None),
+    };
+    let Some(value) = values.get(name) else {
+        let mut known: Vec<_> = values.keys().collect();
+        known.sort_unstable();
+        bail!("unknown placeholder {name:?}. Known placeholders are: {known:?}");
+    };
+    match filter {
+        None => Ok(value.clone()),
+        Some("doc") => Ok(doc_comment(value)),
+        Some(filter) => bail!("unknown filter {filter:?}"),
+    }
+}
+
+/// Prefixes each line of `value` with `//! ` to make it module documentation
+pub(crate) fn doc_comment(value: &str) -> String {
+    value
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                "//!".to_string()
+            } else {
+                format!("//! {line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values() -> HashMap<&'static str, String> {
+        HashMap::from([
+            ("title", "Two Sum".to_string()),
+            ("description", "Line one\n\nLine two".to_string()),
+        ])
+    }
+
+    #[test]
+    fn replaces_placeholders() {
+        let actual = render("//! {{title}} and {{ title }}\n", &values()).unwrap();
+        assert_eq!(actual, "//! Two Sum and Two Sum\n");
+    }
+
+    #[test]
+    fn doc_filter() {
+        let actual = render("{{description | doc}}\n", &values()).unwrap();
+        assert_eq!(actual, "//! Line one\n//!\n//! Line two\n");
+    }
+
+    #[test]
+    fn unknown_placeholder() {
+        assert!(render("{{missing}}", &values()).is_err());
+    }
+
+    #[test]
+    fn unknown_filter() {
+        assert!(render("{{title | shout}}", &values()).is_err());
+    }
+
+    #[test]
+    fn unterminated_placeholder() {
+        assert!(render("{{title", &values()).is_err());
+    }
+}
@@ -1,56 +1,85 @@
 use anyhow::{Context, bail};
-use log::{error, info};
+use log::{error, info, warn};
 use std::{
     env,
     fs::{self, OpenOptions, remove_file},
     io::Write,
-    path::PathBuf,
-    process::Command,
+    path::Path,
+    process::{Command, Stdio},
 };
 
-use crate::tool::does_user_confirm;
+use crate::tool::{
+    core::{generate::SEPARATOR, helpers::module_path::ModulePath},
+    does_user_confirm,
+};
 
-/// Updates lib.rs by adding a module declaration for `module_name` only check
-/// for possible duplication if `is_likely_already_exists` is true because it
-/// opens the file twice in that case to avoid reading from and writing to the
-/// file when the common case is expected to be append only
-fn update_lib(module_name: &str, is_likely_already_exist: bool) -> anyhow::Result<()> {
-    info!("Adding {module_name} to libs.rs");
-    let lib_path = PathBuf::from("src/lib.rs");
+/// Adds a module declaration for `module_name` to `declaring_file` (lib.rs or
+/// a mod.rs). Only checks for possible duplication if `is_likely_already_exist`
+/// is true because it opens the file twice in that case to avoid reading from
+/// and writing to the file when the common case is expected to be append only
+fn add_mod_declaration(
+    declaring_file: &Path,
+    module_name: &str,
+    is_likely_already_exist: bool,
+) -> anyhow::Result<()> {
+    info!("Adding {module_name} to {declaring_file:?}");
+    let is_lib = declaring_file == Path::new("src/lib.rs");
 
     // Check to avoid duplicating module declaration
-    if is_likely_already_exist {
+    if is_likely_already_exist && declaring_file.exists() {
         // Note this does not handle multi-line comments with /* */
-        let contents = fs::read_to_string(&lib_path)
-            .context("failed to read from lib.rs to check for existing mod declaration")?;
+        let contents = fs::read_to_string(declaring_file).with_context(|| {
+            format!("failed to read from {declaring_file:?} to check for existing mod declaration")
+        })?;
+        let declaration = format!("mod {module_name};");
         for line in contents.lines() {
-            if !line.trim().starts_with('/') && line.contains(module_name) {
-                info!("lib.rs already contains {module_name} skipping update");
+            let line = line.trim();
+            if line.strip_prefix("pub ").unwrap_or(line) == declaration {
+                info!("{declaring_file:?} already contains {module_name} skipping update");
                 return Ok(());
             }
         }
     }
 
-    let mut lib = OpenOptions::new()
+    let mut file = OpenOptions::new()
         .append(true)
-        .open(&lib_path)
+        // lib.rs is expected to exist, other files are created as needed
+        .create(!is_lib)
+        .open(declaring_file)
         .with_context(|| {
             format!(
                 "Failed to open {:?}",
                 env::current_dir()
                     .expect("Unable to resolve current directory")
-                    .join(lib_path)
+                    .join(declaring_file)
             )
         })?;
 
-    lib.write_all(format!("pub mod {module_name};").as_bytes())
-        .context("write to lib.rs failed")?;
+    file.write_all(format!("pub mod {module_name};\n").as_bytes())
+        .with_context(|| format!("write to {declaring_file:?} failed"))?;
     Ok(())
 }
 
-pub(crate) fn write_file(module_name: &str, module_code: &str) -> anyhow::Result<()> {
-    info!("Writing code to disk for module {module_name}");
-    let path = PathBuf::from(format!("src/{module_name}.rs"));
+/// Adds the declarations of the module and any modules containing it
+fn update_mod_declarations(
+    module_path: &ModulePath,
+    is_likely_already_exist: bool,
+) -> anyhow::Result<()> {
+    for (declaring_file, module_name) in module_path.declarations() {
+        // Modules containing others are shared between problems so always check
+        let is_containing_module = module_name != module_path.name();
+        add_mod_declaration(
+            &declaring_file,
+            module_name,
+            is_likely_already_exist || is_containing_module,
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn write_file(module_path: &ModulePath, module_code: &str) -> anyhow::Result<()> {
+    info!("Writing code to disk for module {module_path}");
+    let path = module_path.file_path();
     // This creates a TOCTOU but the window is small
     let did_file_already_exist = path.exists();
     if did_file_already_exist
@@ -58,6 +87,10 @@ pub(crate) fn write_file(module_name: &str, module_code: &str) -> anyhow::Result
     {
         bail!("aborted at user request");
     }
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create directory '{}'", dir.display()))?;
+    }
     let mut file = OpenOptions::new()
         .write(true)
         .create(true)
@@ -66,7 +99,7 @@ pub(crate) fn write_file(module_name: &str, module_code: &str) -> anyhow::Result
         .with_context(|| format!("Failed to create '{}'", path.display()))?;
     file.write_all(module_code.as_bytes())
         .with_context(|| format!("Failed writing to '{}'", path.display()))?;
-    let lib_update_status = update_lib(module_name, did_file_already_exist);
+    let lib_update_status = update_mod_declarations(module_path, did_file_already_exist);
     if lib_update_status.is_err() {
         error!("Failed to update lib.rs: Performing cleanup of partially completed command");
         // clean up
@@ -81,6 +114,168 @@ pub(crate) fn write_file(module_name: &str, module_code: &str) -> anyhow::Result
         )?;
     }
 
+    run_rustfmt()
+}
+
+/// Writes `module_code` to `path` without adding it to lib.rs
+pub(crate) fn write_standalone_file(path: &Path, module_code: &str) -> anyhow::Result<()> {
+    info!("Writing code to {path:?}");
+    if path.exists() && !(does_user_confirm(format!("{path:?} already exists. Overwrite?"))?) {
+        bail!("aborted at user request");
+    }
+    let code = format_code(module_code).unwrap_or_else(|e| {
+        warn!("Failed to format code, writing it unformatted. Error: {e:?}");
+        module_code.to_string()
+    });
+    fs::write(path, code).with_context(|| format!("Failed writing to '{}'", path.display()))
+}
+
+/// Replaces the part of an existing module from [`SEPARATOR`] onwards with the
+/// corresponding part of `module_code` leaving the solution above it untouched
+pub(crate) fn write_generated_section(
+    module_path: &ModulePath,
+    module_code: &str,
+) -> anyhow::Result<()> {
+    info!("Writing generated section to disk for module {module_path}");
+    let path = module_path.file_path();
+    let existing = fs::read_to_string(&path).with_context(|| {
+        format!(
+            "failed to read '{}'. Only existing modules can have their tests regenerated",
+            path.display()
+        )
+    })?;
+    let new_code = replace_generated_section(&existing, module_code).with_context(|| {
+        format!(
+            "failed to replace generated section of '{}'",
+            path.display()
+        )
+    })?;
+    fs::write(&path, new_code)
+        .with_context(|| format!("Failed writing to '{}'", path.display()))?;
+    run_rustfmt()
+}
+
+/// Keeps everything in `existing` before the separator and takes the separator
+/// and everything after it from `generated`
+pub(crate) fn replace_generated_section(existing: &str, generated: &str) -> anyhow::Result<String> {
+    let Some(existing_idx) = existing.find(SEPARATOR) else {
+        bail!("separator not found in existing code. Expected a line with: {SEPARATOR}")
+    };
+    let Some(generated_idx) = generated.find(SEPARATOR) else {
+        bail!("separator not found in generated code")
+    };
+    Ok(format!(
+        "{}{}",
+        &existing[..existing_idx],
+        &generated[generated_idx..]
+    ))
+}
+
+/// Edition used to format code if the project does not set one
+const DEFAULT_EDITION: &str = "2024";
+
+/// Edition of the project in the current directory, read from its Cargo.toml
+fn project_edition() -> String {
+    fs::read_to_string("Cargo.toml").map_or_else(
+        |e| {
+            warn!("Failed to read Cargo.toml, using edition {DEFAULT_EDITION}. Error: {e}");
+            DEFAULT_EDITION.to_string()
+        },
+        |manifest| edition_from_manifest(&manifest),
+    )
+}
+
+/// Reads `package.edition` from the contents of a Cargo.toml, defaults to
+/// [`DEFAULT_EDITION`] if it is not set or the manifest is not valid
+fn edition_from_manifest(manifest: &str) -> String {
+    manifest
+        .parse::<toml::Table>()
+        .ok()
+        .and_then(|manifest| {
+            manifest
+                .get("package")?
+                .get("edition")?
+                .as_str()
+                .map(ToString::to_string)
+        })
+        .unwrap_or_else(|| DEFAULT_EDITION.to_string())
+}
+
+/// Formats `code` with rustfmt so it can be compared with code already on disk
+pub(crate) fn format_code(code: &str) -> anyhow::Result<String> {
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", &project_edition(), "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("failed to spawn rustfmt")?;
+    child
+        .stdin
+        .take()
+        .context("failed to open stdin of rustfmt")?
+        .write_all(code.as_bytes())
+        .context("failed to write code to rustfmt")?;
+    let output = child
+        .wait_with_output()
+        .context("failed to wait for rustfmt to finish")?;
+    if !output.status.success() {
+        bail!(
+            "rustfmt failed with: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    String::from_utf8(output.stdout).context("output of rustfmt was not valid utf-8")
+}
+
+/// Moves the module at `from` to `to` (only the name is expected to differ)
+/// updating its declaration
+pub(crate) fn rename_module(from: &ModulePath, to: &ModulePath) -> anyhow::Result<()> {
+    info!("Renaming module {from} to {to}");
+    let (from_file, to_file) = (from.file_path(), to.file_path());
+    if to_file.exists() {
+        bail!("{to_file:?} already exists");
+    }
+    let Some((declaring_file, _)) = from.declarations().pop() else {
+        unreachable!("declarations always include the module itself")
+    };
+    let contents = fs::read_to_string(&declaring_file)
+        .with_context(|| format!("failed to read {declaring_file:?}"))?;
+    let new_contents = rename_mod_declaration(&contents, from.name(), to.name())
+        .with_context(|| format!("failed to find declaration of {from} in {declaring_file:?}"))?;
+
+    fs::rename(&from_file, &to_file)
+        .with_context(|| format!("failed to move {from_file:?} to {to_file:?}"))?;
+    if let Err(e) = fs::write(&declaring_file, new_contents) {
+        error!("Failed to update {declaring_file:?}: Moving module back");
+        fs::rename(&to_file, &from_file).with_context(|| {
+            format!("Failed to move {to_file:?} back to {from_file:?} during cleanup")
+        })?;
+        return Err(e).with_context(|| format!("failed writing to {declaring_file:?}"));
+    }
+    Ok(())
+}
+
+/// Replaces the declaration of module `from` with one for `to`
+fn rename_mod_declaration(contents: &str, from: &str, to: &str) -> Option<String> {
+    let declaration = format!("mod {from};");
+    let mut is_found = false;
+    let result = contents
+        .split_inclusive('\n')
+        .map(|line| {
+            let trimmed = line.trim();
+            if trimmed.strip_prefix("pub ").unwrap_or(trimmed) == declaration {
+                is_found = true;
+                line.replacen(&declaration, &format!("mod {to};"), 1)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect();
+    is_found.then_some(result)
+}
+
+pub(crate) fn run_rustfmt() -> anyhow::Result<()> {
     info!("Going to run rustfmt on files");
     Command::new("cargo")
         .arg("fmt")
@@ -89,3 +284,56 @@ pub(crate) fn write_file(module_name: &str, module_code: &str) -> anyhow::Result
         .context("Error running rustfmt")?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn replace_generated_section_keeps_solution() {
+        // Arrange
+        let existing = format!(
+            "//! Header\n\nimpl Solution {{ fn solved() {{}} }}\n\n{SEPARATOR}\n\nmod tests {{ old }}\n"
+        );
+        let generated = format!(
+            "//! Header\n\nimpl Solution {{ todo!() }}\n\n{SEPARATOR}\n\nmod tests {{ new }}\n"
+        );
+        let expected = format!(
+            "//! Header\n\nimpl Solution {{ fn solved() {{}} }}\n\n{SEPARATOR}\n\nmod tests {{ new }}\n"
+        );
+
+        // Act
+        let actual = replace_generated_section(&existing, &generated).unwrap();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn rename_mod_declaration_only_changes_matching_line() {
+        let contents = "pub mod _1_two_sum;\n// mod _1_two_sum_old;\nmod other;\n";
+        let actual = rename_mod_declaration(contents, "_1_two_sum", "p0001_two_sum").unwrap();
+        assert_eq!(
+            actual,
+            "pub mod p0001_two_sum;\n// mod _1_two_sum_old;\nmod other;\n"
+        );
+        assert!(rename_mod_declaration(contents, "missing", "p0001_missing").is_none());
+    }
+
+    #[rstest]
+    #[case("[package]\nname = \"leet\"\nedition = \"2021\"\n", "2021")]
+    #[case("[package]\nname = \"leet\"\n", "2024")]
+    #[case("not toml", "2024")]
+    fn edition_read_from_manifest(#[case] manifest: &str, #[case] expected: &str) {
+        assert_eq!(edition_from_manifest(manifest), expected);
+    }
+
+    #[test]
+    fn replace_generated_section_missing_separator() {
+        let existing = "impl Solution {}\nmod tests {}\n";
+        let generated = format!("impl Solution {{}}\n{SEPARATOR}\nmod tests {{}}\n");
+        assert!(replace_generated_section(existing, &generated).is_err());
+    }
+}
@@ -2,15 +2,23 @@ mod active;
 mod generate;
 mod helpers;
 mod new;
+mod rename_modules;
+mod show;
+mod sync_submissions;
 mod test;
+mod upgrade;
 
 use self::generate::do_generate;
 use crate::tool::cli::{self, Cli};
 use active::do_active;
 use anyhow::{Context, bail};
 use new::do_new;
+use rename_modules::do_rename_modules;
+use show::do_show;
 use std::{env, path::Path};
+use sync_submissions::do_sync_submissions;
 use test::do_test;
+use upgrade::do_upgrade;
 
 /// Entry point used by the tool. The `main.rs` is pretty thin shim around this
 /// function.
@@ -22,7 +30,9 @@ pub fn run(cli: &Cli) -> anyhow::Result<()> {
 
     match &cli.command {
         cli::Commands::Generate(args) => {
-            working_directory_validation()?;
+            if !args.is_independent_of_project() {
+                working_directory_validation()?;
+            }
             do_generate(args)
         }
         cli::Commands::Active(args) => {
@@ -34,6 +44,24 @@ pub fn run(cli: &Cli) -> anyhow::Result<()> {
             do_test()
         }
         cli::Commands::New(args) => do_new(args),
+        cli::Commands::SyncSubmissions => {
+            working_directory_validation()?;
+            do_sync_submissions()
+        }
+        cli::Commands::Upgrade => {
+            working_directory_validation()?;
+            do_upgrade()
+        }
+        cli::Commands::Show(args) => {
+            if args.problem.is_none() {
+                working_directory_validation()?;
+            }
+            do_show(args)
+        }
+        cli::Commands::RenameModules => {
+            working_directory_validation()?;
+            do_rename_modules()
+        }
     }
 }
 
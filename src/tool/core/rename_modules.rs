@@ -0,0 +1,98 @@
+use anyhow::Context;
+use log::{error, info, warn};
+
+use crate::tool::{
+    config_file::ConfigFile,
+    core::{
+        generate::ModuleOptions,
+        helpers::{
+            self, existing_modules::list_existing_modules, module_name::module_name,
+            module_path::ModulePath, problem_metadata::get_problem_metadata, write_to_disk,
+        },
+    },
+    does_user_confirm,
+};
+
+pub(crate) fn do_rename_modules() -> anyhow::Result<()> {
+    let mut config = ConfigFile::load().context("failed to load config")?;
+    let options = ModuleOptions::from(&config);
+    let pattern = options.module_name_pattern();
+    let modules = list_existing_modules().context("failed to list existing modules")?;
+    println!("Found {} modules", modules.len());
+
+    let mut failed = vec![];
+    let mut renames: Vec<(ModulePath, ModulePath)> = vec![];
+    for (i, module) in modules.iter().enumerate() {
+        let Some(title_slug) = &module.title_slug else {
+            warn!(
+                "Skipping {} as no slug was found in its header",
+                module.module_path
+            );
+            continue;
+        };
+        helpers::delay_between_problems(i);
+        let new_name = get_problem_metadata(title_slug)
+            .context("failed to retrieve problem meta data")
+            .and_then(|metadata| module_name(pattern, &metadata, title_slug));
+        match new_name {
+            Ok(new_name) if new_name == module.module_path.name() => {
+                info!("{} already matches the pattern", module.module_path);
+            }
+            Ok(new_name) => {
+                let new_path = module.module_path.with_name(new_name);
+                if renames.iter().any(|(_, other)| *other == new_path) {
+                    error!(
+                        "Multiple modules would be renamed to {new_path}, skipping {}",
+                        module.module_path
+                    );
+                    failed.push(module.module_path.to_string());
+                } else {
+                    renames.push((module.module_path.clone(), new_path));
+                }
+            }
+            Err(e) => {
+                error!("Failed to get new name for {}: {e:?}", module.module_path);
+                failed.push(module.module_path.to_string());
+            }
+        }
+    }
+
+    if renames.is_empty() {
+        println!("No modules need to be renamed to match {pattern:?}");
+    } else {
+        for (from, to) in &renames {
+            println!("{from} -> {to}");
+        }
+        if does_user_confirm(format!("Rename {} modules?", renames.len()))? {
+            let mut renamed_count = 0;
+            for (from, to) in &renames {
+                match write_to_disk::rename_module(from, to) {
+                    Ok(()) => {
+                        renamed_count += 1;
+                        if config.active.as_deref() == Some(from.to_string().as_str()) {
+                            config.active = Some(to.to_string());
+                            config.save().context("failed to save config")?;
+                        }
+                    }
+                    Err(e) => {
+                        error!("Failed to rename {from}: {e:?}");
+                        failed.push(from.to_string());
+                    }
+                }
+            }
+            write_to_disk::run_rustfmt()?;
+            println!("Renamed {renamed_count} modules");
+        } else {
+            println!("No modules renamed");
+        }
+    }
+
+    if !failed.is_empty() {
+        println!(
+            "Failed to rename {} modules: {}",
+            failed.len(),
+            failed.join(", ")
+        );
+    }
+    Ok(())
+}
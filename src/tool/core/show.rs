@@ -0,0 +1,293 @@
+use std::fmt::Write as _;
+
+use anyhow::Context;
+use console::{Style, Term, style};
+
+use crate::tool::{
+    cli,
+    config::Config,
+    config_file::ConfigFile,
+    core::{
+        generate::get_slug_from_args,
+        helpers::{
+            existing_modules::list_existing_modules,
+            problem_description::get_problem_description,
+            problem_metadata::{data_structure::ProblemMetadata, get_problem_metadata},
+        },
+    },
+};
+
+/// Width used if the width of the terminal cannot be determined
+const DEFAULT_WIDTH: usize = 80;
+
+/// Text is not wrapped wider than this even on very wide terminals
+const MAX_WIDTH: usize = 100;
+
+pub(crate) fn do_show(args: &cli::ShowArgs) -> anyhow::Result<()> {
+    let title_slug = match &args.problem {
+        Some(problem) => get_slug_from_args(problem)
+            .with_context(|| format!("expected URL, slug or number but got {problem}"))?,
+        None => get_active_title_slug()?,
+    };
+
+    let metadata =
+        get_problem_metadata(&title_slug).context("failed to retrieve problem meta data")?;
+    let description =
+        get_problem_description(&title_slug).context("failed to retrieve problem description")?;
+
+    let width = Term::stdout()
+        .size_checked()
+        .map_or(DEFAULT_WIDTH, |(_, columns)| usize::from(columns))
+        .min(MAX_WIDTH);
+    print!(
+        "{}",
+        render_problem(&title_slug, &metadata, &description.to_markdown(), width)
+    );
+    Ok(())
+}
+
+/// Finds the slug of the active problem from the header of its module
+fn get_active_title_slug() -> anyhow::Result<String> {
+    let active = ConfigFile::load()
+        .context("failed to load config")?
+        .active
+        .context("no problem specified and no active problem set")?;
+    list_existing_modules()
+        .context("failed to list existing modules")?
+        .into_iter()
+        .find(|module| module.module_path.to_string() == active)
+        .with_context(|| format!("active problem {active} not found in project"))?
+        .title_slug
+        .with_context(|| format!("no slug found in the header of active problem {active}"))
+}
+
+fn render_problem(
+    title_slug: &str,
+    metadata: &ProblemMetadata,
+    description_markdown: &str,
+    width: usize,
+) -> String {
+    let mut result = format!(
+        "{}\n",
+        style(format!("{}. {}", metadata.id, metadata.title)).bold()
+    );
+    let mut details = vec![];
+    if let Some(difficulty) = &metadata.difficulty {
+        let difficulty_style = match difficulty.as_str() {
+            "Easy" => Style::new().green(),
+            "Medium" => Style::new().yellow(),
+            "Hard" => Style::new().red(),
+            _ => Style::new(),
+        };
+        details.push(difficulty_style.apply_to(difficulty).to_string());
+    }
+    if !metadata.topic_tags.is_empty() {
+        details.push(metadata.topic_tags.join(", "));
+    }
+    if !details.is_empty() {
+        result.push_str(&details.join(" | "));
+        result.push('\n');
+    }
+    writeln!(
+        result,
+        "{}\n",
+        style(format!("{}{title_slug}/", Config::LEETCODE_PROBLEM_URL)).dim()
+    )
+    .expect("write! macro failed");
+    result.push_str(&render_markdown(description_markdown, width));
+    result
+}
+
+/// Renders the markdown produced from problem descriptions for the terminal
+fn render_markdown(markdown: &str, width: usize) -> String {
+    let mut result = String::with_capacity(markdown.len());
+    let mut is_in_code_block = false;
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            is_in_code_block = !is_in_code_block;
+            continue;
+        }
+        if is_in_code_block {
+            // Examples are not wrapped to keep the values intact
+            writeln!(result, "    {}", style(line).cyan()).expect("write! macro failed");
+            continue;
+        }
+        if let Some(url) = line
+            .strip_prefix("![")
+            .and_then(|rest| rest.split_once("]("))
+            .and_then(|(_, rest)| rest.strip_suffix(')'))
+        {
+            writeln!(result, "{}", style(format!("[image: {url}]")).dim())
+                .expect("write! macro failed");
+            continue;
+        }
+
+        // Continuation lines of list items are indented to line up with the text
+        let indent_len = line.len() - line.trim_start().len();
+        let trimmed = line.trim_start();
+        let marker_len = if trimmed.starts_with("- ") {
+            2
+        } else {
+            trimmed
+                .split_once(". ")
+                .filter(|(number, _)| {
+                    !number.is_empty() && number.chars().all(|c| c.is_ascii_digit())
+                })
+                .map_or(0, |(number, _)| number.len() + 2)
+        };
+        let (first_prefix, text) = trimmed.split_at(marker_len);
+        let first_prefix = format!("{}{first_prefix}", &line[..indent_len]);
+        let hanging_indent = " ".repeat(indent_len + marker_len);
+
+        for (i, wrapped) in wrap(
+            &parse_inline(text),
+            width - hanging_indent.len().min(width / 2),
+        )
+        .iter()
+        .enumerate()
+        {
+            let prefix = if i == 0 {
+                &first_prefix
+            } else {
+                &hanging_indent
+            };
+            writeln!(result, "{prefix}{wrapped}").expect("write! macro failed");
+        }
+        if text.is_empty() {
+            result.push('\n');
+        }
+    }
+    result
+}
+
+/// Formatting applied to a piece of text
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct InlineStyle {
+    is_bold: bool,
+    is_italic: bool,
+    is_code: bool,
+}
+
+impl InlineStyle {
+    fn apply(self, text: &str) -> String {
+        let mut result = Style::new();
+        if self.is_bold {
+            result = result.bold();
+        }
+        if self.is_italic {
+            result = result.italic();
+        }
+        if self.is_code {
+            result = result.cyan();
+        }
+        result.apply_to(text).to_string()
+    }
+}
+
+/// Splits a line of markdown into pieces of plain text and their formatting
+fn parse_inline(text: &str) -> Vec<(String, InlineStyle)> {
+    let mut result: Vec<(String, InlineStyle)> = vec![];
+    let mut current = InlineStyle::default();
+    let mut chars = text.chars().peekable();
+    let mut push_char = |c: char, style: InlineStyle| match result.last_mut() {
+        Some((text, last_style)) if *last_style == style => text.push(c),
+        _ => result.push((c.to_string(), style)),
+    };
+    while let Some(c) = chars.next() {
+        match c {
+            '`' => current.is_code = !current.is_code,
+            _ if current.is_code => push_char(c, current),
+            '\\' => push_char(chars.next().unwrap_or('\\'), current),
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                current.is_bold = !current.is_bold;
+            }
+            '*' => current.is_italic = !current.is_italic,
+            _ => push_char(c, current),
+        }
+    }
+    result
+}
+
+/// Wraps styled text to `width` columns breaking only on spaces
+fn wrap(pieces: &[(String, InlineStyle)], width: usize) -> Vec<String> {
+    let mut lines = vec![];
+    let mut line = String::new();
+    let mut line_width = 0;
+    // Words can span multiple pieces when the style changes mid-word
+    let mut word = String::new();
+    let mut word_width = 0;
+
+    let mut finish_word = |word: &mut String, word_width: &mut usize| {
+        if word.is_empty() {
+            return;
+        }
+        if line_width > 0 && line_width + 1 + *word_width > width {
+            lines.push(std::mem::take(&mut line));
+            line_width = 0;
+        }
+        if line_width > 0 {
+            line.push(' ');
+            line_width += 1;
+        }
+        line.push_str(word);
+        line_width += *word_width;
+        word.clear();
+        *word_width = 0;
+    };
+
+    for (text, style) in pieces {
+        for (i, part) in text.split(' ').enumerate() {
+            if i > 0 {
+                finish_word(&mut word, &mut word_width);
+            }
+            if !part.is_empty() {
+                word.push_str(&style.apply(part));
+                word_width += console::measure_text_width(part);
+            }
+        }
+    }
+    finish_word(&mut word, &mut word_width);
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::tool::core::helpers::{
+        local_store::tests::{SlugList, insta_settings, title_slugs},
+        problem_description::get_problem_description,
+        problem_metadata::get_problem_metadata,
+    };
+
+    use super::*;
+
+    #[rstest]
+    fn rendered_problem(title_slugs: SlugList, insta_settings: insta::Settings) {
+        for title_slug in title_slugs {
+            let metadata = get_problem_metadata(title_slug).unwrap();
+            let description = get_problem_description(title_slug).unwrap();
+            let rendered = render_problem(title_slug, &metadata, &description.to_markdown(), 60);
+            insta_settings.bind(|| {
+                insta::assert_snapshot!(
+                    format!("show {title_slug}"),
+                    console::strip_ansi_codes(&rendered)
+                );
+            });
+        }
+    }
+
+    #[test]
+    fn wrap_breaks_on_spaces() {
+        let pieces = parse_inline(r"one **two** three `four five` six\[0\]");
+        let actual: Vec<_> = wrap(&pieces, 10)
+            .iter()
+            .map(|line| console::strip_ansi_codes(line).to_string())
+            .collect();
+        assert_eq!(actual, ["one two", "three four", "five", "six[0]"]);
+    }
+}
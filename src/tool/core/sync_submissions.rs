@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use anyhow::Context;
+use log::{error, info};
+
+use crate::tool::{
+    config_file::ConfigFile,
+    core::{
+        generate::{ModuleOptions, create_module_code},
+        helpers::{
+            self, existing_modules::list_existing_modules,
+            submissions::get_accepted_rust_submissions, write_to_disk,
+        },
+    },
+};
+
+pub(crate) fn do_sync_submissions() -> anyhow::Result<()> {
+    let options = ModuleOptions::from(&ConfigFile::load().context("failed to load config")?);
+
+    let existing_slugs: HashMap<String, String> = list_existing_modules()
+        .context("failed to list existing modules")?
+        .into_iter()
+        .filter_map(|module| Some((module.title_slug?, module.module_path.to_string())))
+        .collect();
+
+    let submissions = get_accepted_rust_submissions()
+        .context("failed to get accepted submissions from leetcode")?;
+    let to_generate: Vec<_> = submissions
+        .into_iter()
+        .filter(|submission| {
+            existing_slugs
+                .get(&submission.title_slug)
+                .is_none_or(|name| {
+                    info!(
+                        "Skipping {:?} already in module {name}",
+                        submission.title_slug
+                    );
+                    false
+                })
+        })
+        .collect();
+    println!(
+        "Found {} accepted rust submissions not yet in the project",
+        to_generate.len()
+    );
+
+    let mut failed = vec![];
+    for (i, submission) in to_generate.iter().enumerate() {
+        helpers::delay_between_problems(i);
+        let title_slug = &submission.title_slug;
+        info!("Generating module from submission for {title_slug:?}");
+        let result = create_module_code(title_slug, &options, Some(&submission.code)).and_then(
+            |(module_path, module_code)| {
+                write_to_disk::write_file(&module_path, &module_code)?;
+                Ok(module_path)
+            },
+        );
+        match result {
+            Ok(module_path) => println!("Generated module: {module_path}"),
+            Err(e) => {
+                error!("Failed to generate module for {title_slug:?}: {e:?}");
+                failed.push(title_slug.as_str());
+            }
+        }
+    }
+
+    if !failed.is_empty() {
+        println!(
+            "Failed to generate {} of {} modules: {}",
+            failed.len(),
+            to_generate.len(),
+            failed.join(", ")
+        );
+    }
+
+    Ok(())
+}
@@ -1,48 +1,75 @@
-use std::{fs, process::Command};
+use std::{fs, path::PathBuf, process::Command};
 
 use anyhow::Context;
 use itertools::Itertools;
 use regex::Regex;
 
-use crate::tool::config_file::ConfigFile;
+use crate::tool::{config_file::ConfigFile, core::helpers::module_path::ModulePath};
 
 pub(crate) fn do_test() -> anyhow::Result<()> {
-    let problem_slug = ConfigFile::load()
+    let active: ModulePath = ConfigFile::load()
         .context("failed to load config")?
         .active
-        .context("no active problem")?;
-
-    let lib_rs = fs::read_to_string("src/lib.rs").context("failed to read src/lib.rs")?;
+        .context("no active problem")?
+        .parse()
+        .context("invalid active problem")?;
 
     let exp = Regex::new("(?:pub )?mod ([a-zA-Z\\-\\_0-9]+);").context("failed to create regex")?;
 
-    let new_lib_rs = lib_rs
-        .lines()
-        .map(|line| {
-            if let Some(m) = exp.captures(line) {
-                let name = m.get(1).unwrap().as_str();
+    // Only keep the modules along the path to the active problem in each file
+    let mut originals: Vec<(PathBuf, String)> = vec![];
+    for (declaring_file, module_name) in active.declarations() {
+        let contents = match fs::read_to_string(&declaring_file)
+            .with_context(|| format!("failed to read {declaring_file:?}"))
+        {
+            Ok(contents) => contents,
+            Err(e) => {
+                restore_files(&originals)?;
+                return Err(e);
+            }
+        };
 
-                if name == problem_slug.as_str() {
-                    return line.to_string();
-                }
+        let new_contents = contents
+            .lines()
+            .map(|line| {
+                if let Some(m) = exp.captures(line) {
+                    let name = m.get(1).unwrap().as_str();
 
-                format!("// mod {name};")
-            } else {
-                line.to_string()
-            }
-        })
-        .join("\n");
+                    if name == module_name {
+                        return line.to_string();
+                    }
 
-    fs::write("src/lib.rs", &new_lib_rs).context("failed to write to src/lib.rs")?;
+                    format!("// mod {name};")
+                } else {
+                    line.to_string()
+                }
+            })
+            .join("\n");
 
-    Command::new("cargo")
+        fs::write(&declaring_file, &new_contents)
+            .with_context(|| format!("failed to write to {declaring_file:?}"))?;
+        originals.push((declaring_file, contents));
+    }
+
+    let test_result = Command::new("cargo")
         .arg("test")
         .spawn()
-        .context("failed to spawn cargo test")?
-        .wait()
-        .context("failed to wait for cargo test to finish")?;
+        .context("failed to spawn cargo test")
+        .and_then(|mut child| {
+            child
+                .wait()
+                .context("failed to wait for cargo test to finish")
+        });
+
+    restore_files(&originals)?;
+    test_result?;
 
-    fs::write("src/lib.rs", lib_rs).context("failed to restore src/lib.rs")?;
+    Ok(())
+}
 
+fn restore_files(originals: &[(PathBuf, String)]) -> anyhow::Result<()> {
+    for (path, contents) in originals {
+        fs::write(path, contents).with_context(|| format!("failed to restore {path:?}"))?;
+    }
     Ok(())
 }
@@ -0,0 +1,94 @@
+use std::fs;
+
+use anyhow::Context;
+use log::{error, info, warn};
+use similar::TextDiff;
+
+use crate::tool::{
+    config_file::ConfigFile,
+    core::{
+        generate::{ModuleOptions, create_module_code},
+        helpers::{
+            self,
+            existing_modules::{ExistingModule, list_existing_modules},
+            write_to_disk::{format_code, replace_generated_section},
+        },
+    },
+    does_user_confirm,
+};
+
+pub(crate) fn do_upgrade() -> anyhow::Result<()> {
+    let options = ModuleOptions::from(&ConfigFile::load().context("failed to load config")?);
+    let modules = list_existing_modules().context("failed to list existing modules")?;
+    println!("Found {} modules", modules.len());
+
+    let mut failed = vec![];
+    let mut upgraded_count = 0;
+    for (i, module) in modules.iter().enumerate() {
+        let Some(title_slug) = &module.title_slug else {
+            warn!(
+                "Skipping {} as no slug was found in its header",
+                module.module_path
+            );
+            continue;
+        };
+        helpers::delay_between_problems(i);
+        match upgrade_module(module, title_slug, &options) {
+            Ok(true) => upgraded_count += 1,
+            Ok(false) => {}
+            Err(e) => {
+                error!("Failed to upgrade {}: {e:?}", module.module_path);
+                failed.push(module.module_path.to_string());
+            }
+        }
+    }
+
+    println!("Upgraded {upgraded_count} modules");
+    if !failed.is_empty() {
+        println!(
+            "Failed to upgrade {} modules: {}",
+            failed.len(),
+            failed.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// Returns true if the module was changed on disk
+fn upgrade_module(
+    module: &ExistingModule,
+    title_slug: &str,
+    options: &ModuleOptions,
+) -> anyhow::Result<bool> {
+    info!("Checking {} for upgrade", module.module_path);
+    let existing = fs::read_to_string(&module.path)
+        .with_context(|| format!("failed to read {:?}", module.path))?;
+
+    // Module path is not used as the existing module is updated
+    let (_, generated) = create_module_code(title_slug, options, None)
+        .with_context(|| format!("failed to generate module code for {title_slug:?}"))?;
+    let upgraded = format_code(&replace_generated_section(&existing, &generated)?)
+        .context("failed to format upgraded code")?;
+
+    if upgraded == existing {
+        println!("{} is up to date", module.module_path);
+        return Ok(false);
+    }
+
+    let path_display = module.path.display().to_string();
+    println!(
+        "{}",
+        TextDiff::from_lines(&existing, &upgraded)
+            .unified_diff()
+            .header(&path_display, &path_display)
+    );
+    if !does_user_confirm(format!("Apply changes to {path_display:?}?"))? {
+        println!("Skipped {}", module.module_path);
+        return Ok(false);
+    }
+
+    fs::write(&module.path, upgraded)
+        .with_context(|| format!("failed writing to {:?}", module.path))?;
+    println!("Upgraded {}", module.module_path);
+    Ok(true)
+}